@@ -0,0 +1,120 @@
+use syn::{
+    Error, Expr, ExprLit, ExprPath, Lit, Meta, Path, Result, Type, TypePath, spanned::Spanned,
+};
+
+/// Conversion from a parsed attribute value into a Rust value.
+///
+/// Intended for [`NsAttr`](`crate::NsAttr`) implementations working with
+/// [`Meta`] or [`Expr`] arguments, so each conversion reports a span-accurate
+/// error instead of being re-written by hand:
+///
+/// ```text
+/// Expected String Literal
+/// ```
+///
+/// Implemented for `String`, `bool`, `u64`, [`Path`] and [`Type`].
+///
+/// Example:
+/// ```ignore
+/// for meta in attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+///     match meta.path().get_ident().map(Ident::to_string).as_deref() {
+///         Some("rename") => rename = Some(String::from_meta(&meta)?),
+///         Some("skip") => skip = bool::from_meta(&meta)?,
+///         _ => return Err(Error::new_spanned(meta.path(), "Unknown Key")),
+///     }
+/// }
+/// ```
+pub trait AttrValue: Sized {
+    /// Converts the value expression of `key = value`.
+    fn from_expr(expr: &Expr) -> Result<Self>;
+
+    /// Converts a whole `key = value` argument.
+    ///
+    /// The default implementation accepts only the name-value form
+    /// and defers to [`AttrValue::from_expr`].
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::NameValue(nv) => Self::from_expr(&nv.value),
+            _ => Err(Error::new(meta.span(), "Expected `key = value`")),
+        }
+    }
+}
+
+impl AttrValue for String {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match lit(expr) {
+            Some(Lit::Str(lit)) => Ok(lit.value()),
+            _ => Err(Error::new(expr.span(), "Expected String Literal")),
+        }
+    }
+}
+
+impl AttrValue for bool {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match lit(expr) {
+            Some(Lit::Bool(lit)) => Ok(lit.value),
+            _ => Err(Error::new(expr.span(), "Expected Boolean Literal")),
+        }
+    }
+
+    /// Accepts the bare key as `true`, in addition to `key = bool`.
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(true),
+            Meta::NameValue(nv) => Self::from_expr(&nv.value),
+            Meta::List(list) => Err(Error::new(
+                list.delimiter.span().join(),
+                "Expected `key` Or `key = bool`",
+            )),
+        }
+    }
+}
+
+impl AttrValue for u64 {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match lit(expr) {
+            Some(Lit::Int(lit)) => lit.base10_parse(),
+            _ => Err(Error::new(expr.span(), "Expected Integer Literal")),
+        }
+    }
+}
+
+/// Accepts both `key = a::b` and `key = "a::b"`.
+impl AttrValue for Path {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) => Ok(path.clone()),
+            _ => match lit(expr) {
+                Some(Lit::Str(lit)) => lit.parse(),
+                _ => Err(Error::new(expr.span(), "Expected Path")),
+            },
+        }
+    }
+}
+
+/// Accepts both `key = a::B` and `key = "Vec<a::B>"`, since most types are not valid expressions.
+impl AttrValue for Type {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        match expr {
+            Expr::Path(ExprPath { qself, path, .. }) => Ok(Type::Path(TypePath {
+                qself: qself.clone(),
+                path: path.clone(),
+            })),
+            _ => match lit(expr) {
+                Some(Lit::Str(lit)) => lit.parse(),
+                _ => Err(Error::new(expr.span(), "Expected Type")),
+            },
+        }
+    }
+}
+
+/// Returns the literal of `expr`, looking through invisible groups from `macro_rules!` expansion.
+fn lit(expr: &Expr) -> Option<&Lit> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => Some(lit),
+        Expr::Group(group) => lit(&group.expr),
+        _ => None,
+    }
+}
@@ -0,0 +1,36 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::FieldBinding;
+
+/// Describes how a field is accessed in a generated expression.
+///
+/// - `Value` produces `receiver.member`.
+/// - `Ref` produces `&receiver.member`.
+/// - `RefMut` produces `&mut receiver.member`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessMode {
+    Value,
+    Ref,
+    RefMut,
+}
+
+impl FieldBinding {
+    /// Generates an expression accessing this field on `receiver`.
+    ///
+    /// Works for both named and positional members:
+    ///
+    /// ```text
+    /// self.name
+    /// &self.0
+    /// &mut other.name
+    /// ```
+    pub fn access(&self, receiver: &TokenStream, by: AccessMode) -> TokenStream {
+        let member = self.member();
+        match by {
+            AccessMode::Value => quote! { #receiver.#member },
+            AccessMode::Ref => quote! { &#receiver.#member },
+            AccessMode::RefMut => quote! { &mut #receiver.#member },
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Attribute;
+
+use crate::{AccessMode, FieldBinding, StructBinding};
+
+impl FieldBinding {
+    /// Generates a getter method returning a shared reference to this field.
+    ///
+    /// The method is named after the binding identifier and inherits the field's
+    /// visibility and `#[doc]` attributes:
+    ///
+    /// ```text
+    /// /// docs of `name`
+    /// pub fn name(&self) -> &Ty { &self.name }
+    /// ```
+    pub fn getter(&self) -> TokenStream {
+        let vis = self.vis();
+        let docs = self.docs();
+        let ident = self.ident();
+        let ty = self.ty();
+        let access = self.access(&quote! { self }, AccessMode::Ref);
+
+        quote! {
+            #(#docs)*
+            #vis fn #ident(&self) -> &#ty {
+                #access
+            }
+        }
+    }
+
+    /// Generates a setter method replacing the value of this field.
+    ///
+    /// The method is named `set_{ident}` and inherits the field's
+    /// visibility and `#[doc]` attributes:
+    ///
+    /// ```text
+    /// /// docs of `name`
+    /// pub fn set_name(&mut self, value: Ty) { self.name = value; }
+    /// ```
+    pub fn setter(&self) -> TokenStream {
+        let vis = self.vis();
+        let docs = self.docs();
+        let ident = format_ident!("set_{}", self.ident());
+        let ty = self.ty();
+        let access = self.access(&quote! { self }, AccessMode::Value);
+
+        quote! {
+            #(#docs)*
+            #vis fn #ident(&mut self, value: #ty) {
+                #access = value;
+            }
+        }
+    }
+
+    fn docs(&self) -> impl Iterator<Item = &Attribute> {
+        self.attrs()
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+    }
+}
+
+impl StructBinding {
+    /// Generates a [`FieldBinding::getter`] for every field, in declaration order.
+    pub fn getters(&self) -> TokenStream {
+        let getters = self.field_bindings().iter().map(FieldBinding::getter);
+
+        quote! { #(#getters)* }
+    }
+
+    /// Generates a [`FieldBinding::setter`] for every field, in declaration order.
+    pub fn setters(&self) -> TokenStream {
+        let setters = self.field_bindings().iter().map(FieldBinding::setter);
+
+        quote! { #(#setters)* }
+    }
+}
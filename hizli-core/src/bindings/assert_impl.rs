@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{Generics, Path, spanned::Spanned};
+
+use crate::{FieldBinding, anon_const_scope};
+
+/// Generates a compile-time assertion that every field type implements `trait_path`.
+///
+/// For a struct `Foo<T> { a: A, b: T }` and the trait `Trait` this produces:
+///
+/// ```text
+/// const _: () = {
+///     fn assert_impl<T: ?Sized + Trait>() {}
+///     fn assert_fields<T>() {
+///         assert_impl::<A>();
+///         assert_impl::<T>();
+///     }
+/// };
+/// ```
+///
+/// Each call is spanned at the field's type, so a missing implementation is reported
+/// on the offending field instead of deep inside the generated code.
+///
+/// `generics` should carry the bounds the generated impl will use, otherwise fields
+/// whose types are generic parameters fail the assertion.
+pub fn assert_impl(
+    field_bindings: &[FieldBinding],
+    trait_path: &Path,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_gen, _, where_cl) = generics.split_for_impl();
+
+    let asserts = field_bindings.iter().map(|fb| {
+        let ty = fb.ty();
+        quote_spanned! {ty.span()=> assert_impl::<#ty>(); }
+    });
+
+    anon_const_scope(quote! {
+        #[allow(dead_code)]
+        fn assert_impl<T: ?::core::marker::Sized + #trait_path>() {}
+
+        #[allow(dead_code)]
+        fn assert_fields #impl_gen () #where_cl {
+            #(#asserts)*
+        }
+    })
+}
@@ -0,0 +1,89 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Error, Member, Result, spanned::Spanned};
+
+use crate::{StructBinding, bindings::expect};
+
+impl StructBinding {
+    /// Generates the constructor body for this struct from one expression per field.
+    ///
+    /// The result is wrapped according to the [`FieldType`](`crate::FieldType`) and is meant
+    /// to follow a path such as `Self` or `Self::Variant`. Named fields whose expression is
+    /// exactly the binding identifier use field init shorthand:
+    ///
+    /// ```text
+    /// { a, b: other.b.clone() }
+    /// (binding_0, other.1.clone())
+    /// ```
+    ///
+    /// Expressions are matched to fields in declaration order. Fields left out by
+    /// [`StructBinding::partition`] are initialized with `Default::default()`, so their types
+    /// must implement [`Default`]; otherwise the generated code fails to compile with the error
+    /// pointing at the omitted field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one expression per field binding, which would otherwise
+    /// generate a constructor missing fields. See [`StructBinding::try_construct`] for the
+    /// fallible form.
+    pub fn construct<I>(&self, exprs: I) -> TokenStream
+    where
+        I: IntoIterator<Item = TokenStream>,
+    {
+        expect(self.try_construct(exprs))
+    }
+
+    /// Generates the constructor body like [`StructBinding::construct`], returning an error
+    /// instead of panicking if there is not exactly one expression per field binding.
+    pub fn try_construct<I>(&self, exprs: I) -> Result<TokenStream>
+    where
+        I: IntoIterator<Item = TokenStream>,
+    {
+        let exprs: Vec<TokenStream> = exprs.into_iter().collect();
+        if exprs.len() != self.field_bindings().len() {
+            return Err(Error::new(
+                Span::call_site(),
+                format!(
+                    "Expected {} Expressions, One Per Field, Found {}",
+                    self.field_bindings().len(),
+                    exprs.len()
+                ),
+            ));
+        }
+        let mut inits: Vec<(&Member, TokenStream)> = self
+            .field_bindings()
+            .iter()
+            .zip(exprs)
+            .map(|(fb, expr)| (fb.member(), expr))
+            .collect();
+        inits.extend(self.omitted().iter().map(|member| {
+            let default = quote_spanned! {member.span()=> ::core::default::Default::default() };
+            (member, default)
+        }));
+        inits.sort_by_key(|(member, _)| self.position(member));
+
+        let inits = inits.into_iter().map(|(member, expr)| match member {
+            Member::Named(id) if *id == expr.to_string() => quote! { #id },
+            Member::Named(id) => quote! { #id: #expr },
+            Member::Unnamed(_) => expr,
+        });
+
+        Ok(self.field_type().wrap(quote! { #(#inits),* }))
+    }
+
+    /// Generates a constructor body rebuilding the struct from its own bindings,
+    /// the counterpart of a destructuring pattern:
+    ///
+    /// ```text
+    /// { a, b }
+    /// (binding_0, binding_1)
+    /// ```
+    pub fn reconstruct(&self) -> TokenStream {
+        let idents = self.field_bindings().iter().map(|fb| {
+            let ident = fb.ident();
+            quote! { #ident }
+        });
+
+        self.construct(idents)
+    }
+}
@@ -0,0 +1,53 @@
+use syn::{DataEnum, Field, Fields, Variant};
+
+use crate::{EnumBinding, FieldBinding, StructBinding, VariantBinding, bindings::expect};
+
+/// Equivalent to [`FieldBinding::try_new`], panicking if the index of an unnamed field does
+/// not fit in a tuple index (`u32`).
+impl From<(usize, Field)> for FieldBinding {
+    fn from((idx, field): (usize, Field)) -> Self {
+        expect(Self::try_new((idx, &field)))
+    }
+}
+
+/// Equivalent to [`StructBinding::new`].
+impl From<&Fields> for StructBinding {
+    fn from(fields: &Fields) -> Self {
+        Self::new(fields)
+    }
+}
+
+/// Equivalent to [`StructBinding::new`].
+impl From<Fields> for StructBinding {
+    fn from(fields: Fields) -> Self {
+        Self::new(&fields)
+    }
+}
+
+/// Equivalent to [`VariantBinding::new`].
+impl From<&Variant> for VariantBinding {
+    fn from(variant: &Variant) -> Self {
+        Self::new(variant)
+    }
+}
+
+/// Equivalent to [`VariantBinding::new`].
+impl From<Variant> for VariantBinding {
+    fn from(variant: Variant) -> Self {
+        Self::new(&variant)
+    }
+}
+
+/// Equivalent to [`EnumBinding::new`].
+impl From<&DataEnum> for EnumBinding {
+    fn from(data: &DataEnum) -> Self {
+        Self::new(data)
+    }
+}
+
+/// Equivalent to [`EnumBinding::new`].
+impl From<DataEnum> for EnumBinding {
+    fn from(data: DataEnum) -> Self {
+        Self::from_parts(data.variants.into_iter().map(Into::into).collect())
+    }
+}
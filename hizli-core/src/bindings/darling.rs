@@ -0,0 +1,95 @@
+use darling::ast::{Data, Fields, Style};
+use syn::{Error, Field, Variant};
+
+use crate::{
+    EnumBinding, FieldBinding, FieldType, StructBinding, VariantBinding, data::unsupported_kind,
+    derive_span,
+};
+
+/// Maps darling's `Struct`, `Tuple` and `Unit` styles to `Named`, `Unnamed` and `Unit`.
+impl From<Style> for FieldType {
+    fn from(style: Style) -> Self {
+        match style {
+            Style::Struct => Self::Named,
+            Style::Tuple => Self::Unnamed,
+            Style::Unit => Self::Unit,
+        }
+    }
+}
+
+/// Maps `Named`, `Unnamed` and `Unit` to darling's `Struct`, `Tuple` and `Unit` styles.
+impl From<FieldType> for Style {
+    fn from(field_type: FieldType) -> Self {
+        match field_type {
+            FieldType::Named => Self::Struct,
+            FieldType::Unnamed => Self::Tuple,
+            FieldType::Unit => Self::Unit,
+        }
+    }
+}
+
+/// Binds the fields collected by darling, see [`StructBinding::try_new`].
+impl TryFrom<Fields<Field>> for StructBinding {
+    type Error = Error;
+
+    fn try_from(fields: Fields<Field>) -> Result<Self, Error> {
+        let (style, fields) = fields.split();
+        let field_bindings = fields
+            .into_iter()
+            .enumerate()
+            .map(|(idx, field)| FieldBinding::try_new((idx, &field)))
+            .collect::<Result<_, Error>>()?;
+        Ok(Self::from_parts(field_bindings, style.into()))
+    }
+}
+
+impl StructBinding {
+    /// Binds a struct body collected by darling, rejecting enums like
+    /// [`StructBinding::try_from_input`] does, with the error pointing at the
+    /// [`derive_span`](`crate::derive_span`).
+    pub fn try_from_darling(data: Data<Variant, Field>, derive_name: &str) -> Result<Self, Error> {
+        match data {
+            Data::Struct(fields) => fields.try_into(),
+            Data::Enum(_) => Err(unsupported_kind(derive_span(), "Enum", derive_name)),
+        }
+    }
+}
+
+impl EnumBinding {
+    /// Binds an enum body collected by darling, rejecting structs like
+    /// [`EnumBinding::try_from_input`] does, with the error pointing at the
+    /// [`derive_span`](`crate::derive_span`).
+    pub fn try_from_darling(data: Data<Variant, Field>, derive_name: &str) -> Result<Self, Error> {
+        match data {
+            Data::Enum(variants) => Ok(Self::from_parts(
+                variants
+                    .iter()
+                    .map(VariantBinding::try_new)
+                    .collect::<Result<_, Error>>()?,
+            )),
+            Data::Struct(_) => Err(unsupported_kind(derive_span(), "Struct", derive_name)),
+        }
+    }
+}
+
+/// Hands the field bindings to darling, keeping their order and layout.
+impl From<StructBinding> for Fields<FieldBinding> {
+    fn from(binding: StructBinding) -> Self {
+        let (field_bindings, field_type) = binding.into_parts();
+        Self::new(field_type.into(), field_bindings)
+    }
+}
+
+/// Hands the field bindings to darling as a struct body.
+impl From<StructBinding> for Data<VariantBinding, FieldBinding> {
+    fn from(binding: StructBinding) -> Self {
+        Self::Struct(binding.into())
+    }
+}
+
+/// Hands the variant bindings to darling as an enum body.
+impl From<EnumBinding> for Data<VariantBinding, FieldBinding> {
+    fn from(binding: EnumBinding) -> Self {
+        Self::Enum(binding.into_parts())
+    }
+}
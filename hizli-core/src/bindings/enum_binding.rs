@@ -0,0 +1,91 @@
+use std::slice::Iter;
+
+#[cfg(feature = "full")]
+use syn::ItemEnum;
+use syn::{Data, DataEnum, DeriveInput, Generics, Ident, Result};
+
+use crate::{
+    VariantBinding,
+    data::{respan, unsupported},
+    derive_span,
+};
+
+/// Represents an enum’s variant bindings.
+///
+/// Wraps one [`VariantBinding`] per variant, in declaration order,
+/// so enums can be processed uniformly regardless of where they were parsed from.
+#[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq))]
+pub struct EnumBinding {
+    variant_bindings: Vec<VariantBinding>,
+}
+
+impl EnumBinding {
+    /// Constructs a new [`EnumBinding`] from a [`syn::DataEnum`] node.
+    pub fn new(data: &DataEnum) -> Self {
+        Self {
+            variant_bindings: data.variants.iter().map(VariantBinding::new).collect(),
+        }
+    }
+
+    /// Constructs a new [`EnumBinding`] from a [`syn::ItemEnum`],
+    /// as parsed by attribute or function-like macros.
+    #[cfg(feature = "full")]
+    pub fn from_item_enum(item: &ItemEnum) -> Self {
+        Self {
+            variant_bindings: item.variants.iter().map(VariantBinding::new).collect(),
+        }
+    }
+
+    /// Constructs a new [`EnumBinding`] from a [`syn::DeriveInput`], rejecting structs and unions
+    /// like [`EnumOnly`](`crate::EnumOnly`) does, with the error pointing at the
+    /// [`derive_span`](`crate::derive_span`).
+    ///
+    /// Returns the binding together with the input’s identifier and generics.
+    ///
+    /// # Usage
+    /// ```ignore
+    /// let (binding, ident, generics) = EnumBinding::try_from_input(&input, "MyMacro")?;
+    /// let (impl_gen, type_gen, where_cl) = generics.split_for_impl();
+    /// ```
+    pub fn try_from_input<'a>(
+        input: &'a DeriveInput,
+        derive_name: &str,
+    ) -> Result<(Self, &'a Ident, &'a Generics)> {
+        let binding = match &input.data {
+            Data::Enum(e) => Self {
+                variant_bindings: e
+                    .variants
+                    .iter()
+                    .map(VariantBinding::try_new)
+                    .collect::<Result<_>>()?,
+            },
+            other => {
+                return Err(respan(unsupported(other, derive_name), derive_span()));
+            }
+        };
+        Ok((binding, &input.ident, &input.generics))
+    }
+
+    pub(crate) fn from_parts(variant_bindings: Vec<VariantBinding>) -> Self {
+        Self { variant_bindings }
+    }
+
+    #[cfg(feature = "darling")]
+    pub(crate) fn into_parts(self) -> Vec<VariantBinding> {
+        self.variant_bindings
+    }
+
+    /// Returns all [`VariantBinding`]s belonging to this enum, in declaration order.
+    pub fn variant_bindings(&self) -> &[VariantBinding] {
+        &self.variant_bindings
+    }
+
+    /// Iterates over the [`VariantBinding`]s of this enum in declaration order.
+    ///
+    /// The iterator is double-ended, so the last variant can be reached with
+    /// `.rev()` or `.next_back()` without collecting.
+    pub fn iter(&self) -> Iter<'_, VariantBinding> {
+        self.variant_bindings.iter()
+    }
+}
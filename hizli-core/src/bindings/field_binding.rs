@@ -1,39 +1,96 @@
-use syn::{Field, Fields, Ident, Index, Member, spanned::Spanned};
+use proc_macro2::Span;
+use syn::{
+    Attribute, Error, Field, Fields, Ident, Index, Member, Result, Type, Visibility,
+    spanned::Spanned,
+};
+
+use crate::member_to_ident;
 
 /// Represents a single field binding within a struct, tuple struct, or enum variant.
 ///
 /// Provides both the [`Ident`] (binding name used in generated code) and
-/// [`Member`] (the AST member for accessing the field by name or index).
+/// [`Member`] (the AST member for accessing the field by name or index),
+/// along with the field's declared [`Type`], [`Visibility`] and attributes.
 #[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq))]
 pub struct FieldBinding {
     ident: Ident,
     member: Member,
+    ty: Type,
+    vis: Visibility,
+    attrs: Vec<Attribute>,
 }
 
 impl FieldBinding {
+    /// Creates a new [`FieldBinding`] from a field and its index position,
+    /// like [`FieldBinding::try_new`].
+    ///
+    /// # Panics
+    /// Panics if the index of an unnamed field does not fit in a tuple index (`u32`).
+    #[deprecated(note = "use `FieldBinding::try_new`, which reports a tuple index overflow")]
+    pub fn new(field: (usize, &Field)) -> Self {
+        expect(Self::try_new(field))
+    }
+
     /// Creates a new [`FieldBinding`] from a field and its index position.
     ///
     /// - Named fields use their existing identifier.
     /// - Unnamed (tuple) fields are assigned synthetic identifiers
-    ///   in the form of `binding_{index}`.
-    pub fn new((idx, field): (usize, &Field)) -> Self {
+    ///   in the form of `binding_{index}`, spanned at the field so diagnostics
+    ///   mentioning them point at it.
+    ///
+    /// Returns an error spanned at the field if the index of an unnamed field does not fit in
+    /// a tuple index (`u32`).
+    pub fn try_new((idx, field): (usize, &Field)) -> Result<Self> {
+        let span = field.span();
+        let (attrs, vis, ty) = (field.attrs.clone(), field.vis.clone(), field.ty.clone());
         match field.ident.clone() {
             Some(ident) => {
                 let member = Member::Named(ident.clone());
-                Self { ident, member }
+                Ok(Self {
+                    ident,
+                    member,
+                    ty,
+                    vis,
+                    attrs,
+                })
             }
             None => {
-                let member = Member::Unnamed(Index {
-                    index: idx.try_into().unwrap_or_default(),
-                    span: field.span(),
-                });
-                let ident = format!("binding_{idx}");
-                let ident = Ident::new(&ident, field.span());
-                Self { ident, member }
+                let index = match idx.try_into() {
+                    Ok(ok) => ok,
+                    Err(_) => {
+                        return Err(Error::new(
+                            span,
+                            format!("Field Index {idx} Exceeds The Maximum Tuple Index"),
+                        ));
+                    }
+                };
+                let member = Member::Unnamed(Index { index, span });
+                let ident = member_to_ident(&member, "binding_");
+                Ok(Self {
+                    ident,
+                    member,
+                    ty,
+                    vis,
+                    attrs,
+                })
             }
         }
     }
 
+    /// Replaces the span of the binding identifier, keeping its name.
+    ///
+    /// By default the identifier carries the span of the field it binds. Use this to point
+    /// diagnostics that mention the binding elsewhere, such as at a helper attribute.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.ident.set_span(span);
+        self
+    }
+
+    pub(crate) fn set_ident(&mut self, ident: Ident) {
+        self.ident = ident;
+    }
+
     /// Returns the identifier used for this binding in generated code.
     pub fn ident(&self) -> &Ident {
         &self.ident
@@ -44,8 +101,47 @@ impl FieldBinding {
         &self.member
     }
 
-    /// Creates a vector of [`FieldBinding`]s for all fields in a [`syn::Fields`] node.
+    /// Returns the declared [`Type`] of this field.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+
+    /// Returns the declared [`Visibility`] of this field.
+    pub fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+
+    /// Returns the attributes declared on this field.
+    pub fn attrs(&self) -> &[Attribute] {
+        &self.attrs
+    }
+
+    /// Creates a vector of [`FieldBinding`]s for all fields in a [`syn::Fields`] node,
+    /// in declaration order.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`FieldBinding::new`].
+    #[deprecated(
+        note = "use `FieldBinding::try_from_fields`, which reports a tuple index overflow"
+    )]
     pub fn from_fields(fields: &Fields) -> Vec<Self> {
-        fields.iter().enumerate().map(Self::new).collect()
+        expect(Self::try_from_fields(fields))
+    }
+
+    /// Creates a vector of [`FieldBinding`]s for all fields in a [`syn::Fields`] node,
+    /// returning the first error produced by [`FieldBinding::try_new`].
+    pub fn try_from_fields(fields: &Fields) -> Result<Vec<Self>> {
+        fields.iter().enumerate().map(Self::try_new).collect()
+    }
+}
+
+/// Unwraps the result of the fallible form of a panicking binding method, such as
+/// [`StructBinding::new`], panicking with the error message.
+///
+/// [`StructBinding::new`]: crate::StructBinding::new
+pub(crate) fn expect<T>(result: Result<T>) -> T {
+    match result {
+        Ok(ok) => ok,
+        Err(err) => panic!("{err}"),
     }
 }
@@ -7,7 +7,7 @@ use syn::Fields;
 /// Used by [`StructBinding`](`crate::StructBinding`) and [`VariantBinding`](`crate::VariantBinding`) to determine how to wrap
 /// code fragments in parentheses, braces, or nothing when generating patterns
 /// or construction expressions.
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FieldType {
     Unit,
     Named,
@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::StructBinding;
+
+impl StructBinding {
+    /// Generates a function parameter list with one parameter per field.
+    ///
+    /// For a struct `Foo { a: A, b: B }` or a tuple struct `Foo(A, B)` this produces:
+    ///
+    /// ```text
+    /// a: A, b: B
+    /// binding_0: A, binding_1: B
+    /// ```
+    ///
+    /// Pair with [`StructBinding::forward_args`] to call a function declared with these parameters.
+    pub fn fn_args(&self) -> TokenStream {
+        let args = self.field_bindings().iter().map(|fb| {
+            let ident = fb.ident();
+            let ty = fb.ty();
+            quote! { #ident: #ty }
+        });
+
+        quote! { #(#args),* }
+    }
+
+    /// Generates the argument list forwarding every binding, in declaration order.
+    ///
+    /// ```text
+    /// a, b
+    /// binding_0, binding_1
+    /// ```
+    pub fn forward_args(&self) -> TokenStream {
+        let idents = self.idents();
+
+        quote! { #(#idents),* }
+    }
+}
@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, ImplItem, ItemImpl, Path, Type};
+
+use crate::SignatureBinding;
+
+/// Represents an `impl` block, including its self type, trait and method signatures.
+///
+/// Intended for attribute macros that inspect or augment existing impls.
+#[derive(Clone)]
+pub struct ImplBlockBinding {
+    item: ItemImpl,
+    methods: Vec<SignatureBinding>,
+}
+
+impl ImplBlockBinding {
+    /// Creates a new [`ImplBlockBinding`] from a parsed [`syn::ItemImpl`].
+    pub fn new(item: &ItemImpl) -> Self {
+        let methods = item
+            .items
+            .iter()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Fn(f) => Some(SignatureBinding::new(&f.sig)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            item: item.clone(),
+            methods,
+        }
+    }
+
+    /// Returns the type the block is implemented for.
+    pub fn self_ty(&self) -> &Type {
+        &self.item.self_ty
+    }
+
+    /// Returns the implemented trait path, or `None` for inherent impls.
+    pub fn trait_path(&self) -> Option<&Path> {
+        self.item.trait_.as_ref().map(|(_, path, _)| path)
+    }
+
+    /// Returns the generics declared on the block.
+    pub fn generics(&self) -> &Generics {
+        &self.item.generics
+    }
+
+    /// Returns the signatures of all methods in the block, in declaration order.
+    pub fn methods(&self) -> &[SignatureBinding] {
+        &self.methods
+    }
+
+    /// Returns the underlying [`syn::ItemImpl`].
+    pub fn item(&self) -> &ItemImpl {
+        &self.item
+    }
+
+    /// Regenerates the impl block with `injected` items appended after the existing ones.
+    ///
+    /// ```text
+    /// impl<T> Trait for Foo<T> where T: Clone {
+    ///     // existing items
+    ///     #injected
+    /// }
+    /// ```
+    pub fn with_items(&self, injected: TokenStream) -> TokenStream {
+        let ItemImpl {
+            attrs,
+            defaultness,
+            unsafety,
+            impl_token,
+            generics,
+            trait_,
+            self_ty,
+            items,
+            ..
+        } = &self.item;
+
+        let (impl_gen, _, where_cl) = generics.split_for_impl();
+        let trait_ = trait_
+            .as_ref()
+            .map(|(bang, path, for_token)| quote! { #bang #path #for_token });
+
+        quote! {
+            #(#attrs)*
+            #defaultness #unsafety #impl_token #impl_gen #trait_ #self_ty #where_cl {
+                #(#items)*
+                #injected
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Member};
+
+/// Converts a [`Member`] into a binding identifier.
+///
+/// - Named members keep their identifier.
+/// - Unnamed members become `{prefix}{index}`, spanned at the index.
+///
+/// With the prefix `"binding_"` this matches the identifiers chosen by [`FieldBinding`](`crate::FieldBinding`).
+pub fn member_to_ident(member: &Member, prefix: &str) -> Ident {
+    match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(idx) => format_ident!("{prefix}{}", idx.index, span = idx.span),
+    }
+}
+
+/// Generates an expression accessing `member` on `self_expr`.
+///
+/// ```text
+/// self.name
+/// other.0
+/// ```
+pub fn ident_for(self_expr: &TokenStream, member: &Member) -> TokenStream {
+    quote! { #self_expr.#member }
+}
@@ -10,21 +10,73 @@
 //!
 //! | Type | Purpose |
 //! |------|----------|
+//! | [`EnumBinding`] | Aggregates variant bindings for an enum. |
 //! | [`FieldBinding`] | Represents a single field binding (named or positional). |
+//! | [`AccessMode`] | Selects value, `&` or `&mut` access in [`FieldBinding::access`]. |
 //! | [`FieldType`] | Describes the field layout (`Unit`, `Named`, or `Unnamed`). |
+//! | [`ImplBlockBinding`] | Wraps an `impl` block with its method signatures. |
+//! | [`member_to_ident`] | Converts bare members into binding identifiers. |
+//! | [`SignatureBinding`] | Wraps a function signature with its inputs as field bindings. |
 //! | [`StructBinding`] | Aggregates field bindings for a struct or tuple struct. |
+//! | [`TraitBinding`] | Wraps a trait with its methods and associated items. |
+//! | `TypeParamUsage` | Maps type parameters to the fields mentioning them (`visit` feature). |
+//! | `mentions_self` | Detects recursive field types mentioning `Self` (`visit` feature). |
+//! | [`UnionBinding`] | Aggregates field bindings for a union. |
 //! | [`VariantBinding`] | Wraps an enum variant with its fields and layout info. |
+//! | [`assert_impl`] | Asserts at compile time that field types implement a trait. |
 //!
 //! These are composed to support flexible generation of token streams for patterns,
 //! destructuring, and initialization in procedural macros.
 
+mod access;
+mod accessors;
+mod assert_impl;
+mod construct;
+mod conversions;
+#[cfg(feature = "darling")]
+mod darling;
+mod enum_binding;
 mod field_binding;
 mod field_type;
+mod fn_args;
+#[cfg(feature = "full")]
+mod impl_block_binding;
+mod member;
+#[cfg(feature = "visit")]
+mod self_reference;
+#[cfg(feature = "full")]
+mod signature_binding;
 mod struct_binding;
+mod struct_pattern;
+#[cfg(feature = "full")]
+mod trait_binding;
+#[cfg(feature = "visit")]
+mod type_param_usage;
+mod union_binding;
 mod variant_binding;
 mod variant_pattern;
+#[cfg(feature = "visit")]
+mod visit;
 
+pub use access::AccessMode;
+pub use assert_impl::assert_impl;
+pub use enum_binding::EnumBinding;
 pub use field_binding::FieldBinding;
+pub(crate) use field_binding::expect;
 pub use field_type::FieldType;
+#[cfg(feature = "full")]
+pub use impl_block_binding::ImplBlockBinding;
+pub use member::{ident_for, member_to_ident};
+#[cfg(feature = "visit")]
+pub use self_reference::mentions_self;
+#[cfg(feature = "full")]
+pub use signature_binding::SignatureBinding;
 pub use struct_binding::StructBinding;
+#[cfg(feature = "full")]
+pub(crate) use struct_binding::remangle;
+#[cfg(feature = "full")]
+pub use trait_binding::{TraitBinding, TraitMethodBinding};
+#[cfg(feature = "visit")]
+pub use type_param_usage::TypeParamUsage;
+pub use union_binding::UnionBinding;
 pub use variant_binding::VariantBinding;
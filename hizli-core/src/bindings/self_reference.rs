@@ -0,0 +1,65 @@
+use syn::{
+    Ident, Path, Type,
+    visit::{self, Visit},
+};
+
+use crate::{FieldBinding, TypeParamUsage};
+
+/// Returns `true` if `ty` syntactically mentions `Self` or the type named `ident`, as the
+/// recursive fields of an AST do with `Box<Expr>`, `Vec<Self>` or `Option<crate::ast::Expr<T>>`.
+///
+/// Bounding such a field type, as in `where Box<Expr<T>>: Clone` on the impl for `Expr<T>`,
+/// makes the bound depend on the impl being written, which the compiler rejects with an overflow.
+/// Bound inference skips these fields, since the impl itself covers them. Types hidden behind
+/// macros or aliases are not seen.
+pub fn mentions_self(ty: &Type, ident: &Ident) -> bool {
+    let mut mentions = SelfMentions {
+        ident,
+        found: false,
+    };
+    mentions.visit_type(ty);
+    mentions.found
+}
+
+/// Looks for a path through `Self` or `ident`.
+struct SelfMentions<'a> {
+    ident: &'a Ident,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for SelfMentions<'_> {
+    fn visit_path(&mut self, path: &'ast Path) {
+        let self_type = path.leading_colon.is_none()
+            && path
+                .segments
+                .first()
+                .is_some_and(|segment| segment.ident == "Self");
+        if self_type
+            || path
+                .segments
+                .iter()
+                .any(|segment| segment.ident == *self.ident)
+        {
+            self.found = true;
+            return;
+        }
+        visit::visit_path(self, path);
+    }
+}
+
+impl FieldBinding {
+    /// Returns `true` if the type of this field mentions `Self` or the type `ident` it belongs
+    /// to, see [`mentions_self`].
+    pub fn is_self_referential(&self, ident: &Ident) -> bool {
+        mentions_self(self.ty(), ident)
+    }
+}
+
+impl TypeParamUsage<'_> {
+    /// Drops the fields whose types mention `Self` or the type `ident`, see [`mentions_self`],
+    /// so [`TypeParamUsage::bounds`] does not bound recursive fields.
+    pub fn skip_self_referential(mut self, ident: &Ident) -> Self {
+        self.retain(|fb| !fb.is_self_referential(ident));
+        self
+    }
+}
@@ -0,0 +1,185 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Field, FieldMutability, FnArg, Ident, ItemFn, Pat, Receiver, ReturnType, Signature, Type,
+    Visibility,
+};
+
+use crate::{
+    FieldBinding,
+    bindings::{expect, remangle},
+};
+
+/// Represents a function signature’s name, receiver, inputs and output.
+///
+/// Typed inputs are exposed as [`FieldBinding`]s so functions can be processed
+/// like the fields of a tuple struct:
+///
+/// - Inputs bound to a plain identifier (`a: u8`) keep that identifier.
+/// - Inputs bound to any other pattern (`(a, b): (u8, u8)`) are assigned
+///   synthetic identifiers in the form of `binding_{index}`, with `_` appended when another
+///   input is already named that way.
+#[derive(Clone)]
+pub struct SignatureBinding {
+    sig: Signature,
+    ident: Ident,
+    receiver: Option<Receiver>,
+    inputs: Vec<FieldBinding>,
+    output: ReturnType,
+    asyncness: bool,
+}
+
+impl SignatureBinding {
+    /// Creates a new [`SignatureBinding`] from a parsed [`syn::Signature`].
+    pub fn new(sig: &Signature) -> Self {
+        let receiver = sig.receiver().cloned();
+        let inputs = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_type) => Some(pat_type),
+                FnArg::Receiver(_) => None,
+            })
+            .map(|pat_type| Field {
+                attrs: pat_type.attrs.clone(),
+                vis: Visibility::Inherited,
+                mutability: FieldMutability::None,
+                ident: match &*pat_type.pat {
+                    Pat::Ident(pat) if pat.subpat.is_none() => Some(pat.ident.clone()),
+                    _ => None,
+                },
+                colon_token: Some(pat_type.colon_token),
+                ty: (*pat_type.ty).clone(),
+            })
+            .collect::<Vec<_>>();
+        let mut inputs: Vec<FieldBinding> = inputs
+            .iter()
+            .enumerate()
+            .map(|input| expect(FieldBinding::try_new(input)))
+            .collect();
+        remangle(&mut inputs, &[]);
+
+        Self {
+            sig: sig.clone(),
+            ident: sig.ident.clone(),
+            receiver,
+            inputs,
+            output: sig.output.clone(),
+            asyncness: sig.asyncness.is_some(),
+        }
+    }
+
+    /// Creates a new [`SignatureBinding`] from a parsed [`syn::ItemFn`].
+    pub fn from_item_fn(item: &ItemFn) -> Self {
+        Self::new(&item.sig)
+    }
+
+    /// Returns the underlying [`syn::Signature`].
+    pub fn signature(&self) -> &Signature {
+        &self.sig
+    }
+
+    /// Returns the identifier of the function.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the `self` receiver, if this is a method.
+    pub fn receiver(&self) -> Option<&Receiver> {
+        self.receiver.as_ref()
+    }
+
+    /// Returns the typed inputs of this function, excluding the receiver.
+    pub fn inputs(&self) -> &[FieldBinding] {
+        &self.inputs
+    }
+
+    /// Returns the declared [`ReturnType`] of this function.
+    pub fn output(&self) -> &ReturnType {
+        &self.output
+    }
+
+    /// Returns the declared return [`Type`], or `None` for the default `()` output.
+    pub fn output_ty(&self) -> Option<&Type> {
+        match &self.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => Some(ty),
+        }
+    }
+
+    /// Returns `true` if the function is declared `async`.
+    pub fn is_async(&self) -> bool {
+        self.asyncness
+    }
+
+    /// Generates the typed parameter list of this function, excluding the receiver.
+    ///
+    /// ```text
+    /// a: u8, binding_1: (u8, u8)
+    /// ```
+    pub fn fn_args(&self) -> TokenStream {
+        let args = self.inputs.iter().map(|fb| {
+            let ident = fb.ident();
+            let ty = fb.ty();
+            quote! { #ident: #ty }
+        });
+
+        quote! { #(#args),* }
+    }
+
+    /// Generates the argument list forwarding every input, excluding the receiver.
+    ///
+    /// ```text
+    /// a, binding_1
+    /// ```
+    pub fn forward_args(&self) -> TokenStream {
+        let idents = self.inputs.iter().map(|fb| fb.ident());
+
+        quote! { #(#idents),* }
+    }
+
+    /// Regenerates this signature with every input bound to its binding identifier,
+    /// so a generated body can refer to all inputs through [`SignatureBinding::forward_args`].
+    ///
+    /// ```text
+    /// async fn name<T>(&self, a: u8, binding_1: (u8, u8)) -> T where T: Default
+    /// ```
+    pub fn bound_signature(&self) -> TokenStream {
+        let Signature {
+            constness,
+            asyncness,
+            unsafety,
+            abi,
+            fn_token,
+            ident,
+            generics,
+            output,
+            ..
+        } = &self.sig;
+        let where_cl = &generics.where_clause;
+        let args = self.fn_args();
+        let inputs = match (&self.receiver, self.inputs.is_empty()) {
+            (Some(receiver), true) => quote! { #receiver },
+            (Some(receiver), false) => quote! { #receiver, #args },
+            (None, _) => args,
+        };
+
+        quote! {
+            #constness #asyncness #unsafety #abi #fn_token #ident #generics(#inputs) #output #where_cl
+        }
+    }
+
+    /// Generates a call to `callee` forwarding every input, awaiting it if this function is `async`.
+    ///
+    /// ```text
+    /// inner(a, binding_1)
+    /// self.inner(a, binding_1).await
+    /// ```
+    pub fn forward_call(&self, callee: &TokenStream) -> TokenStream {
+        let args = self.forward_args();
+        match self.asyncness {
+            true => quote! { #callee(#args).await },
+            false => quote! { #callee(#args) },
+        }
+    }
+}
@@ -1,6 +1,47 @@
-use syn::Fields;
+use std::slice::Iter;
 
-use crate::{FieldBinding, FieldType};
+use proc_macro2::Span;
+use quote::format_ident;
+#[cfg(feature = "full")]
+use syn::ItemStruct;
+use syn::{Data, DeriveInput, Fields, Generics, Ident, Member, Result};
+
+use crate::{
+    FieldBinding, FieldType,
+    bindings::expect,
+    data::{respan, unsupported},
+    derive_span,
+};
+
+/// Renames the bindings of `field_bindings` whose identifier is in `reserved`, and the synthetic
+/// `binding_{index}` identifiers of positional fields shared with another binding, as when a
+/// function input is literally named `binding_1`.
+///
+/// Colliding identifiers get `_` appended until they are unique, keeping their span.
+pub(crate) fn remangle(field_bindings: &mut [FieldBinding], reserved: &[Ident]) {
+    for idx in 0..field_bindings.len() {
+        let mut ident = field_bindings[idx].ident().clone();
+        let synthetic = matches!(field_bindings[idx].member(), Member::Unnamed(_));
+        let shared = |ident: &Ident| {
+            field_bindings
+                .iter()
+                .enumerate()
+                .any(|(other, fb)| other != idx && fb.ident() == ident)
+        };
+        let collides = reserved.contains(&ident) || (synthetic && shared(&ident));
+        if !collides {
+            continue;
+        }
+
+        let taken = |ident: &Ident| {
+            reserved.contains(ident) || field_bindings.iter().any(|fb| fb.ident() == ident)
+        };
+        while taken(&ident) {
+            ident = format_ident!("{}_", ident);
+        }
+        field_bindings[idx].set_ident(ident);
+    }
+}
 
 /// Represents a struct or tuple struct’s field bindings and layout.
 ///
@@ -8,27 +49,198 @@ use crate::{FieldBinding, FieldType};
 /// [`FieldType`], enabling consistent code generation across different
 /// struct forms (unit, named, unnamed).
 #[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq))]
 pub struct StructBinding {
     field_bindings: Vec<FieldBinding>,
     field_type: FieldType,
+    /// The members of the original fields left out by [`StructBinding::partition`].
+    omitted: Vec<Member>,
+    /// The members of every original field in declaration order, kept across partitions.
+    declared: Vec<Member>,
 }
 
 impl StructBinding {
     /// Constructs a new [`StructBinding`] from a [`syn::Fields`] node.
+    ///
+    /// # Panics
+    /// Panics if the index of an unnamed field does not fit in a tuple index (`u32`),
+    /// see [`StructBinding::try_new`] for the fallible form.
     pub fn new(fields: &Fields) -> Self {
+        expect(Self::try_new(fields))
+    }
+
+    /// Constructs a new [`StructBinding`] from a [`syn::Fields`] node whose binding identifiers
+    /// avoid every identifier in `reserved`.
+    ///
+    /// Use this when the generated body already declares locals (such as `input` or `f`)
+    /// that a binding could shadow. Colliding bindings are renamed by appending `_`
+    /// until they are unique, keeping their original span; patterns and constructors
+    /// generated from the binding account for the new names.
+    pub fn new_reserved(fields: &Fields, reserved: &[Ident]) -> Self {
+        let mut res = Self::new(fields);
+        res.reserve(reserved);
+        res
+    }
+
+    pub(crate) fn reserve(&mut self, reserved: &[Ident]) {
+        remangle(&mut self.field_bindings, reserved);
+    }
+
+    /// Replaces the span of every binding identifier, see [`FieldBinding::with_span`].
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.field_bindings = self
+            .field_bindings
+            .into_iter()
+            .map(|fb| fb.with_span(span))
+            .collect();
+        self
+    }
+
+    /// Constructs a new [`StructBinding`] from a [`syn::Fields`] node,
+    /// propagating errors from [`FieldBinding::try_new`].
+    pub fn try_new(fields: &Fields) -> Result<Self> {
+        Ok(Self::from_parts(
+            FieldBinding::try_from_fields(fields)?,
+            FieldType::new(fields),
+        ))
+    }
+
+    pub(crate) fn from_parts(field_bindings: Vec<FieldBinding>, field_type: FieldType) -> Self {
+        let declared = field_bindings
+            .iter()
+            .map(|fb| fb.member().clone())
+            .collect();
         Self {
-            field_bindings: FieldBinding::from_fields(fields),
-            field_type: FieldType::new(fields),
+            field_bindings,
+            field_type,
+            omitted: Vec::new(),
+            declared,
         }
     }
 
-    /// Returns all [`FieldBinding`]s belonging to this struct.
+    #[cfg(feature = "darling")]
+    pub(crate) fn into_parts(self) -> (Vec<FieldBinding>, FieldType) {
+        (self.field_bindings, self.field_type)
+    }
+
+    /// Constructs a new [`StructBinding`] from a [`syn::ItemStruct`],
+    /// as parsed by attribute or function-like macros.
+    #[cfg(feature = "full")]
+    pub fn from_item_struct(item: &ItemStruct) -> Self {
+        Self::new(&item.fields)
+    }
+
+    /// Constructs a new [`StructBinding`] from a [`syn::DeriveInput`], rejecting enums and unions
+    /// like [`StructOnly`](`crate::StructOnly`) does, with the error pointing at the
+    /// [`derive_span`](`crate::derive_span`).
+    ///
+    /// Returns the binding together with the input’s identifier and generics.
+    ///
+    /// # Usage
+    /// ```ignore
+    /// let (binding, ident, generics) = StructBinding::try_from_input(&input, "MyMacro")?;
+    /// let (impl_gen, type_gen, where_cl) = generics.split_for_impl();
+    /// ```
+    pub fn try_from_input<'a>(
+        input: &'a DeriveInput,
+        derive_name: &str,
+    ) -> Result<(Self, &'a Ident, &'a Generics)> {
+        let binding = match &input.data {
+            Data::Struct(s) => Self::try_new(&s.fields)?,
+            other => {
+                return Err(respan(unsupported(other, derive_name), derive_span()));
+            }
+        };
+        Ok((binding, &input.ident, &input.generics))
+    }
+
+    /// Returns all [`FieldBinding`]s belonging to this struct, in declaration order.
     pub fn field_bindings(&self) -> &[FieldBinding] {
         &self.field_bindings
     }
 
+    /// Iterates over the [`FieldBinding`]s of this struct in declaration order.
+    ///
+    /// The iterator is double-ended, so the last field can be reached with
+    /// `.rev()` or `.next_back()` without collecting.
+    pub fn iter(&self) -> Iter<'_, FieldBinding> {
+        self.field_bindings.iter()
+    }
+
+    /// Iterates over the [`Member`] of every field in declaration order, ready for `quote!`
+    /// repetitions such as `#(self.#members),*`.
+    pub fn members(&self) -> impl DoubleEndedIterator<Item = &Member> + ExactSizeIterator + Clone {
+        self.field_bindings.iter().map(FieldBinding::member)
+    }
+
+    /// Iterates over the binding identifier of every field in declaration order, ready for
+    /// `quote!` repetitions such as `#(#idents),*`.
+    pub fn idents(&self) -> impl DoubleEndedIterator<Item = &Ident> + ExactSizeIterator + Clone {
+        self.field_bindings.iter().map(FieldBinding::ident)
+    }
+
     /// Returns the [`FieldType`] describing this struct’s layout.
     pub fn field_type(&self) -> FieldType {
         self.field_type
     }
+
+    /// Returns the members of the fields left out of this binding by
+    /// [`StructBinding::partition`], in declaration order, empty for a complete binding.
+    pub fn omitted(&self) -> &[Member] {
+        &self.omitted
+    }
+
+    /// Splits the fields into the ones `include` accepts and the rest, in declaration order.
+    ///
+    /// Both halves keep the original layout: their [`pattern`](`StructBinding::pattern`) ignores
+    /// the fields of the other half with `..` or `_`, and their
+    /// [`construct`](`StructBinding::construct`) fills them with `Default::default()`, so each
+    /// half can destructure and rebuild the whole struct on its own:
+    ///
+    /// ```text
+    /// { a, c, .. }             (binding_0, _, binding_2)
+    /// { b, .. }                (_, binding_1, ..)
+    /// ```
+    ///
+    /// This is how derives honor `#[skip]`-like attributes, binding only the fields they use.
+    pub fn partition<F>(&self, mut include: F) -> (Self, Self)
+    where
+        F: FnMut(&FieldBinding) -> bool,
+    {
+        let (included, excluded): (Vec<_>, Vec<_>) = self
+            .field_bindings
+            .iter()
+            .cloned()
+            .partition(|fb| include(fb));
+        let omitted = |field_bindings: &[FieldBinding]| {
+            self.declared
+                .iter()
+                .filter(|member| field_bindings.iter().all(|fb| fb.member() != *member))
+                .cloned()
+                .collect()
+        };
+        let (included_omitted, excluded_omitted) = (omitted(&included), omitted(&excluded));
+        let included = Self {
+            field_bindings: included,
+            field_type: self.field_type,
+            omitted: included_omitted,
+            declared: self.declared.clone(),
+        };
+        let excluded = Self {
+            field_bindings: excluded,
+            field_type: self.field_type,
+            omitted: excluded_omitted,
+            declared: self.declared.clone(),
+        };
+        (included, excluded)
+    }
+
+    /// Returns the declaration position of `member` among the original fields, used to merge
+    /// bound and omitted fields back in order.
+    pub(crate) fn position(&self, member: &Member) -> usize {
+        self.declared
+            .iter()
+            .position(|declared| declared == member)
+            .unwrap_or(usize::MAX)
+    }
 }
@@ -0,0 +1,118 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Member;
+
+use crate::{FieldBinding, StructBinding};
+
+impl StructBinding {
+    /// Generates the destructuring pattern binding every field, without a leading path.
+    ///
+    /// ```text
+    /// { a, b }
+    /// (binding_0, binding_1)
+    /// ```
+    ///
+    /// Named fields bound to an identifier other than their own name use `name: ident`.
+    /// Fields left out by [`StructBinding::partition`] are ignored with `..`, or `_` when a
+    /// later positional field is still bound.
+    pub fn pattern(&self) -> TokenStream {
+        let mut bindings: Vec<TokenStream> = self
+            .field_bindings()
+            .iter()
+            .map(|fb| {
+                let ident = fb.ident();
+                match fb.member() {
+                    Member::Named(name) if name != ident => quote! { #name: #ident },
+                    _ => quote! { #ident },
+                }
+            })
+            .collect();
+
+        if !self.omitted().is_empty() {
+            if let Member::Unnamed(_) = self.omitted()[0] {
+                bindings = self.positional_pattern();
+            } else {
+                bindings.push(quote! { .. });
+            }
+        }
+
+        self.field_type().wrap(quote! {
+            #(#bindings),*
+        })
+    }
+
+    /// Generates a destructuring pattern binding only the fields `bind` accepts and matching
+    /// every other field with `_`, without a leading path.
+    ///
+    /// Unlike `..`, every position is spelled out, so the pattern keeps the arity of the fields
+    /// and still only matches that exact shape:
+    ///
+    /// ```text
+    /// { a, b: _ }
+    /// (_, binding_1, _)
+    /// ```
+    ///
+    /// Fields left out by [`StructBinding::partition`] are matched with `_` too.
+    pub fn ignore_pattern<F>(&self, mut bind: F) -> TokenStream
+    where
+        F: FnMut(&FieldBinding) -> bool,
+    {
+        let ignore = |member: &Member| match member {
+            Member::Named(name) => quote! { #name: _ },
+            Member::Unnamed(_) => quote! { _ },
+        };
+        let mut entries: Vec<(&Member, TokenStream)> = self
+            .field_bindings()
+            .iter()
+            .map(|fb| {
+                let ident = fb.ident();
+                let pattern = match fb.member() {
+                    member if !bind(fb) => ignore(member),
+                    Member::Named(name) if name != ident => quote! { #name: #ident },
+                    _ => quote! { #ident },
+                };
+                (fb.member(), pattern)
+            })
+            .collect();
+        entries.extend(self.omitted().iter().map(|member| (member, ignore(member))));
+        entries.sort_by_key(|(member, _)| self.position(member));
+        let entries = entries.into_iter().map(|(_, pattern)| pattern);
+
+        self.field_type().wrap(quote! {
+            #(#entries),*
+        })
+    }
+
+    /// Returns the positional bindings of a partitioned tuple struct, with `_` for the omitted
+    /// fields and `..` once only omitted fields remain.
+    fn positional_pattern(&self) -> Vec<TokenStream> {
+        let len = self.field_bindings().len() + self.omitted().len();
+        let last_bound = self
+            .field_bindings()
+            .iter()
+            .filter_map(|fb| match fb.member() {
+                Member::Unnamed(index) => Some(index.index as usize),
+                Member::Named(_) => None,
+            })
+            .max();
+
+        let mut bindings = Vec::with_capacity(len);
+        for pos in 0..len {
+            if last_bound.is_none_or(|last| pos > last) {
+                bindings.push(quote! { .. });
+                break;
+            }
+            let bound = self.field_bindings().iter().find(
+                |fb| matches!(fb.member(), Member::Unnamed(index) if index.index as usize == pos),
+            );
+            bindings.push(match bound {
+                Some(fb) => {
+                    let ident = fb.ident();
+                    quote! { #ident }
+                }
+                None => quote! { _ },
+            });
+        }
+        bindings
+    }
+}
@@ -0,0 +1,168 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident, ItemTrait, TraitItem, TraitItemConst, Type};
+
+use crate::SignatureBinding;
+
+/// Represents a method declared in a trait.
+///
+/// Combines the method’s [`SignatureBinding`] with whether the trait
+/// provides a default body for it.
+#[derive(Clone)]
+pub struct TraitMethodBinding {
+    signature: SignatureBinding,
+    has_default: bool,
+}
+
+impl TraitMethodBinding {
+    /// Returns the [`SignatureBinding`] of this method.
+    pub fn signature(&self) -> &SignatureBinding {
+        &self.signature
+    }
+
+    /// Returns `true` if the trait provides a default body for this method.
+    pub fn has_default(&self) -> bool {
+        self.has_default
+    }
+}
+
+/// Represents a trait definition, including its methods, associated types and constants.
+///
+/// Intended for macros generating delegating, mocking or forwarding impls of a trait.
+#[derive(Clone)]
+pub struct TraitBinding {
+    ident: Ident,
+    generics: Generics,
+    methods: Vec<TraitMethodBinding>,
+    assoc_types: Vec<Ident>,
+    assoc_consts: Vec<TraitItemConst>,
+}
+
+impl TraitBinding {
+    /// Creates a new [`TraitBinding`] from a parsed [`syn::ItemTrait`].
+    pub fn new(item: &ItemTrait) -> Self {
+        let mut methods = Vec::new();
+        let mut assoc_types = Vec::new();
+        let mut assoc_consts = Vec::new();
+
+        for trait_item in &item.items {
+            match trait_item {
+                TraitItem::Fn(f) => methods.push(TraitMethodBinding {
+                    signature: SignatureBinding::new(&f.sig),
+                    has_default: f.default.is_some(),
+                }),
+                TraitItem::Type(t) => assoc_types.push(t.ident.clone()),
+                TraitItem::Const(c) => assoc_consts.push(c.clone()),
+                _ => {}
+            }
+        }
+
+        Self {
+            ident: item.ident.clone(),
+            generics: item.generics.clone(),
+            methods,
+            assoc_types,
+            assoc_consts,
+        }
+    }
+
+    /// Returns the identifier of the trait.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the generics declared on the trait.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
+    /// Returns all methods of the trait, in declaration order.
+    pub fn methods(&self) -> &[TraitMethodBinding] {
+        &self.methods
+    }
+
+    /// Returns the identifiers of all associated types, in declaration order.
+    pub fn assoc_types(&self) -> &[Ident] {
+        &self.assoc_types
+    }
+
+    /// Returns all associated constants, in declaration order.
+    pub fn assoc_consts(&self) -> &[TraitItemConst] {
+        &self.assoc_consts
+    }
+
+    /// Generates an impl of this trait for `self_ty` delegating every item to `inner_ty`.
+    ///
+    /// `inner` is the expression reaching the delegate from `self`, such as `self.0`.
+    /// For a trait `Trait { type Out; fn get(&self, a: u8) -> Self::Out; }` this produces:
+    ///
+    /// ```text
+    /// impl Trait for SelfTy {
+    ///     type Out = <Inner as Trait>::Out;
+    ///     fn get(&self, a: u8) -> Self::Out { <Inner as Trait>::get(&self.0, a) }
+    /// }
+    /// ```
+    ///
+    /// Receivers are forwarded as `&inner`, `&mut inner` or `inner`; typed receivers such as
+    /// `self: Box<Self>` are not supported. Generic parameters of `self_ty` are not declared.
+    pub fn delegate_impl(
+        &self,
+        self_ty: &Type,
+        inner_ty: &Type,
+        inner: &TokenStream,
+    ) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_gen, type_gen, where_cl) = self.generics.split_for_impl();
+        let trait_path = quote! { #ident #type_gen };
+        let qualified = quote! { <#inner_ty as #trait_path> };
+
+        let types = self.assoc_types.iter().map(|ty| {
+            quote! { type #ty = #qualified::#ty; }
+        });
+
+        let consts = self.assoc_consts.iter().map(|c| {
+            let id = &c.ident;
+            let ty = &c.ty;
+            quote! { const #id: #ty = #qualified::#id; }
+        });
+
+        let methods = self.methods.iter().map(|method| {
+            let sig = method.signature();
+            let method_id = sig.ident();
+            let bound = sig.bound_signature();
+            let args = sig.forward_args();
+
+            let receiver =
+                sig.receiver().map(
+                    |receiver| match (&receiver.reference, &receiver.mutability) {
+                        (Some(_), Some(_)) => quote! { &mut #inner },
+                        (Some(_), None) => quote! { &#inner },
+                        (None, _) => quote! { #inner },
+                    },
+                );
+            let all_args = match (receiver, sig.inputs().is_empty()) {
+                (Some(receiver), true) => receiver,
+                (Some(receiver), false) => quote! { #receiver, #args },
+                (None, _) => args,
+            };
+            let call = match sig.is_async() {
+                true => quote! { #qualified::#method_id(#all_args).await },
+                false => quote! { #qualified::#method_id(#all_args) },
+            };
+
+            quote! {
+                #bound {
+                    #call
+                }
+            }
+        });
+
+        quote! {
+            impl #impl_gen #trait_path for #self_ty #where_cl {
+                #(#types)*
+                #(#consts)*
+                #(#methods)*
+            }
+        }
+    }
+}
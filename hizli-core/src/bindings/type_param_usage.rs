@@ -0,0 +1,147 @@
+use quote::{ToTokens, quote_spanned};
+use syn::{
+    GenericParam, Generics, Ident, Path, TypeParamBound, WherePredicate, parse_quote,
+    spanned::Spanned,
+    visit::{self, Visit},
+};
+
+use crate::{EnumBinding, FieldBinding, StructBinding};
+
+/// Maps every type parameter of a type to the fields whose types mention it.
+///
+/// Built by [`StructBinding::type_param_usage`] and [`EnumBinding::type_param_usage`], it lets a
+/// derive bound the field types that use a parameter instead of the parameter itself, and point
+/// a missing bound at the field that needs it:
+///
+/// ```ignore
+/// let usage = binding
+///     .type_param_usage(&input.generics)
+///     .skip_self_referential(&input.ident);
+/// let mut generics = input.generics.clone();
+/// generics.make_where_clause().predicates.extend(usage.bounds(&parse_quote!(Clone)));
+/// ```
+///
+/// A type mentions `T` through any path starting with `T`, such as `T`, `Vec<T>`,
+/// `<T as Trait>::Output` or `T::Item`. Types hidden behind macros are not seen.
+#[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct TypeParamUsage<'a> {
+    params: Vec<(&'a Ident, Vec<&'a FieldBinding>)>,
+}
+
+impl<'a> TypeParamUsage<'a> {
+    /// Finds the fields of `field_bindings` mentioning each type parameter of `generics`.
+    pub fn new<I>(generics: &'a Generics, field_bindings: I) -> Self
+    where
+        I: IntoIterator<Item = &'a FieldBinding>,
+    {
+        let mut params: Vec<(&Ident, Vec<&FieldBinding>)> = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(ty) => Some((&ty.ident, Vec::new())),
+                _ => None,
+            })
+            .collect();
+        for fb in field_bindings {
+            let mut mentions = Mentions {
+                params: params.iter().map(|(ident, _)| *ident).collect(),
+                found: Vec::new(),
+            };
+            mentions.visit_type(fb.ty());
+            for (ident, fields) in params.iter_mut() {
+                if mentions.found.contains(ident) {
+                    fields.push(fb);
+                }
+            }
+        }
+        Self { params }
+    }
+
+    /// Returns the fields whose types mention `param`, in declaration order, or an empty slice
+    /// when `param` is unused or not a type parameter.
+    pub fn fields(&self, param: &Ident) -> &[&'a FieldBinding] {
+        self.params
+            .iter()
+            .find(|(ident, _)| *ident == param)
+            .map_or(&[], |(_, fields)| fields)
+    }
+
+    /// Keeps only the fields accepted by `keep`.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&FieldBinding) -> bool) {
+        for (_, fields) in self.params.iter_mut() {
+            fields.retain(|fb| keep(fb));
+        }
+    }
+
+    /// Iterates over every type parameter, in declaration order, with the fields mentioning it.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Ident, &[&'a FieldBinding])> {
+        self.params
+            .iter()
+            .map(|(ident, fields)| (*ident, fields.as_slice()))
+    }
+
+    /// Iterates over the type parameters no field mentions, such as the `T` of a
+    /// `PhantomData`-free marker, which need no bound.
+    pub fn unused(&self) -> impl Iterator<Item = &'a Ident> {
+        self.iter()
+            .filter(|(_, fields)| fields.is_empty())
+            .map(|(ident, _)| ident)
+    }
+
+    /// Returns one `FieldType: bound` predicate per distinct field type mentioning a type
+    /// parameter, spanned at the field type so an unsatisfied bound is reported on the field.
+    pub fn bounds(&self, bound: &TypeParamBound) -> Vec<WherePredicate> {
+        let mut seen = Vec::new();
+        let mut bounds = Vec::new();
+        for (_, fields) in self.iter() {
+            for fb in fields {
+                let ty = fb.ty();
+                let key = ty.to_token_stream().to_string();
+                if seen.contains(&key) {
+                    continue;
+                }
+                seen.push(key);
+                let predicate = quote_spanned! {ty.span()=> #ty: #bound };
+                bounds.push(parse_quote!(#predicate));
+            }
+        }
+        bounds
+    }
+}
+
+/// Collects the parameters of `params` that the visited paths start with.
+struct Mentions<'a> {
+    params: Vec<&'a Ident>,
+    found: Vec<&'a Ident>,
+}
+
+impl<'ast> Visit<'ast> for Mentions<'_> {
+    fn visit_path(&mut self, path: &'ast Path) {
+        if path.leading_colon.is_none() {
+            let first = path.segments.first().map(|segment| &segment.ident);
+            for param in &self.params {
+                if first == Some(*param) && !self.found.contains(param) {
+                    self.found.push(param);
+                }
+            }
+        }
+        visit::visit_path(self, path);
+    }
+}
+
+impl StructBinding {
+    /// Maps the type parameters of `generics` to the fields of this struct mentioning them,
+    /// see [`TypeParamUsage`].
+    pub fn type_param_usage<'a>(&'a self, generics: &'a Generics) -> TypeParamUsage<'a> {
+        TypeParamUsage::new(generics, self.iter())
+    }
+}
+
+impl EnumBinding {
+    /// Maps the type parameters of `generics` to the fields of every variant mentioning them,
+    /// see [`TypeParamUsage`].
+    pub fn type_param_usage<'a>(&'a self, generics: &'a Generics) -> TypeParamUsage<'a> {
+        TypeParamUsage::new(generics, self.iter().flat_map(|vb| vb.iter()))
+    }
+}
@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+#[cfg(feature = "full")]
+use syn::ItemUnion;
+use syn::{DataUnion, Fields};
+
+use crate::{AccessMode, FieldBinding, bindings::expect};
+
+/// Represents a union’s field bindings.
+///
+/// Union fields are always named, so each [`FieldBinding`] behaves like the field of a named struct.
+/// Reading a union field is `unsafe`; the access helpers on this type wrap
+/// the generated expression in an `unsafe` block, leaving the soundness argument to the caller.
+#[derive(Clone)]
+pub struct UnionBinding {
+    field_bindings: Vec<FieldBinding>,
+}
+
+impl UnionBinding {
+    /// Constructs a new [`UnionBinding`] from a [`syn::DataUnion`] node.
+    pub fn new(data: &DataUnion) -> Self {
+        Self {
+            field_bindings: expect(FieldBinding::try_from_fields(&Fields::Named(
+                data.fields.clone(),
+            ))),
+        }
+    }
+
+    /// Constructs a new [`UnionBinding`] from a [`syn::ItemUnion`],
+    /// as parsed by attribute or function-like macros.
+    #[cfg(feature = "full")]
+    pub fn from_item_union(item: &ItemUnion) -> Self {
+        Self {
+            field_bindings: expect(FieldBinding::try_from_fields(&Fields::Named(
+                item.fields.clone(),
+            ))),
+        }
+    }
+
+    /// Returns all [`FieldBinding`]s belonging to this union, in declaration order.
+    pub fn field_bindings(&self) -> &[FieldBinding] {
+        &self.field_bindings
+    }
+
+    /// Generates an `unsafe` expression reading `field` from `receiver`.
+    ///
+    /// ```text
+    /// unsafe { &self.name }
+    /// ```
+    ///
+    /// The caller must ensure `field` is the active field whenever the expression is evaluated.
+    pub fn unsafe_access(
+        &self,
+        field: &FieldBinding,
+        receiver: &TokenStream,
+        by: AccessMode,
+    ) -> TokenStream {
+        let access = field.access(receiver, by);
+
+        quote! { unsafe { #access } }
+    }
+
+    /// Generates the constructor body initializing the union through `field`.
+    ///
+    /// ```text
+    /// { name: expr }
+    /// ```
+    pub fn construct(&self, field: &FieldBinding, expr: TokenStream) -> TokenStream {
+        let member = field.member();
+
+        quote! { { #member: #expr } }
+    }
+}
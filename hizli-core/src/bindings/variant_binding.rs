@@ -1,4 +1,7 @@
-use syn::{Ident, Variant};
+use std::slice::Iter;
+
+use proc_macro2::Span;
+use syn::{Ident, Member, Result, Variant};
 
 use crate::{FieldBinding, FieldType, StructBinding};
 
@@ -7,6 +10,7 @@ use crate::{FieldBinding, FieldType, StructBinding};
 /// Combines a variant identifier and its [`StructBinding`] so that enum
 /// variants can be treated like structs in downstream code generation.
 #[derive(Clone)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq))]
 pub struct VariantBinding {
     ident: Ident,
     struct_binding: StructBinding,
@@ -14,6 +18,9 @@ pub struct VariantBinding {
 
 impl VariantBinding {
     /// Creates a new [`VariantBinding`] from a parsed [`syn::Variant`].
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`StructBinding::new`].
     pub fn new(variant: &Variant) -> Self {
         Self {
             ident: variant.ident.clone(),
@@ -21,16 +28,65 @@ impl VariantBinding {
         }
     }
 
+    /// Creates a new [`VariantBinding`] from a parsed [`syn::Variant`] whose binding identifiers
+    /// avoid every identifier in `reserved`, see [`StructBinding::new_reserved`].
+    pub fn new_reserved(variant: &Variant, reserved: &[Ident]) -> Self {
+        Self {
+            ident: variant.ident.clone(),
+            struct_binding: StructBinding::new_reserved(&variant.fields, reserved),
+        }
+    }
+
+    /// Creates a new [`VariantBinding`] from a parsed [`syn::Variant`],
+    /// propagating errors from [`FieldBinding::try_new`].
+    pub fn try_new(variant: &Variant) -> Result<Self> {
+        Ok(Self {
+            ident: variant.ident.clone(),
+            struct_binding: StructBinding::try_new(&variant.fields)?,
+        })
+    }
+
+    /// Replaces the span of every binding identifier, see [`FieldBinding::with_span`].
+    ///
+    /// The identifier of the variant itself keeps its span.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.struct_binding = self.struct_binding.with_span(span);
+        self
+    }
+
     /// Returns the identifier of the variant.
     pub fn ident(&self) -> &Ident {
         &self.ident
     }
 
-    /// Returns a reference to the field bindings of this variant.
+    /// Returns the [`StructBinding`] describing this variant’s fields.
+    pub fn struct_binding(&self) -> &StructBinding {
+        &self.struct_binding
+    }
+
+    /// Returns a reference to the field bindings of this variant, in declaration order.
     pub fn field_bindings(&self) -> &[FieldBinding] {
         self.struct_binding.field_bindings()
     }
 
+    /// Iterates over the field bindings of this variant in declaration order,
+    /// see [`StructBinding::iter`].
+    pub fn iter(&self) -> Iter<'_, FieldBinding> {
+        self.struct_binding.iter()
+    }
+
+    /// Iterates over the [`Member`] of every field of this variant in declaration order,
+    /// see [`StructBinding::members`].
+    pub fn members(&self) -> impl DoubleEndedIterator<Item = &Member> + ExactSizeIterator + Clone {
+        self.struct_binding.members()
+    }
+
+    /// Iterates over the binding identifier of every field of this variant in declaration order,
+    /// see [`StructBinding::idents`].
+    pub fn idents(&self) -> impl DoubleEndedIterator<Item = &Ident> + ExactSizeIterator + Clone {
+        self.struct_binding.idents()
+    }
+
     /// Returns the [`FieldType`] describing this variant’s field layout.
     pub fn field_type(&self) -> FieldType {
         self.struct_binding.field_type()
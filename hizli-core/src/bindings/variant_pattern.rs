@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::VariantBinding;
+use crate::{FieldBinding, VariantBinding};
 
 impl VariantBinding {
     /// Generates a token pattern representing this variant’s binding form.
@@ -21,13 +21,91 @@ impl VariantBinding {
     /// depending on the variant’s [`FieldType`](`crate::FieldType`).
     pub fn variant_pattern(&self) -> TokenStream {
         let variant_id = self.ident();
-        let bindings = self.field_bindings().iter().map(|fb| fb.ident());
-        let pattern = self.field_type().wrap(quote! {
-            #(#bindings),*
-        });
+        let pattern = self.struct_binding().pattern();
 
         quote! {
             #variant_id #pattern
         }
     }
+
+    /// Generates this variant’s pattern binding only the fields `bind` accepts and matching the
+    /// others with `_`, see [`StructBinding::ignore_pattern`](`crate::StructBinding::ignore_pattern`).
+    ///
+    /// ```text
+    /// Foo(_, binding_1)
+    /// ```
+    pub fn ignore_pattern<F>(&self, bind: F) -> TokenStream
+    where
+        F: FnMut(&FieldBinding) -> bool,
+    {
+        let variant_id = self.ident();
+        let pattern = self.struct_binding().ignore_pattern(bind);
+
+        quote! {
+            #variant_id #pattern
+        }
+    }
+
+    /// Generates this variant’s binding pattern prefixed with `enum_path`.
+    ///
+    /// [`VariantBinding::variant_pattern`] expects the caller to prefix `Self::`, which only
+    /// works inside an impl of the enum. This form accepts any path to the enum instead:
+    ///
+    /// ```text
+    /// MyEnum::Foo(binding_0, binding_1)
+    /// crate::ast::MyEnum::<T>::Foo { field_a, field_b }
+    /// ```
+    pub fn qualified_pattern(&self, enum_path: &TokenStream) -> TokenStream {
+        let pattern = self.variant_pattern();
+
+        quote! { #enum_path::#pattern }
+    }
+
+    /// Generates a constructor for this variant prefixed with `enum_path`,
+    /// see [`StructBinding::construct`](`crate::StructBinding::construct`).
+    ///
+    /// ```text
+    /// MyEnum::Foo { a, b: other.b.clone() }
+    /// ```
+    pub fn qualified_construct<I>(&self, enum_path: &TokenStream, exprs: I) -> TokenStream
+    where
+        I: IntoIterator<Item = TokenStream>,
+    {
+        let variant_id = self.ident();
+        let init = self.struct_binding().construct(exprs);
+
+        quote! { #enum_path::#variant_id #init }
+    }
+
+    /// Generates an `if let` expression matching `scrutinee` against this variant.
+    ///
+    /// ```text
+    /// if let Self::Foo { a, b } = self { #body }
+    /// ```
+    pub fn if_let(&self, scrutinee: &TokenStream, body: TokenStream) -> TokenStream {
+        let pattern = self.qualified_pattern(&quote! { Self });
+
+        quote! {
+            if let #pattern = #scrutinee {
+                #body
+            }
+        }
+    }
+
+    /// Generates a `let-else` statement binding this variant’s fields from `scrutinee`.
+    ///
+    /// ```text
+    /// let Self::Foo(binding_0) = self else { #else_body };
+    /// ```
+    ///
+    /// `else_body` must diverge, for example by returning or panicking.
+    pub fn let_else(&self, scrutinee: &TokenStream, else_body: TokenStream) -> TokenStream {
+        let pattern = self.qualified_pattern(&quote! { Self });
+
+        quote! {
+            let #pattern = #scrutinee else {
+                #else_body
+            };
+        }
+    }
 }
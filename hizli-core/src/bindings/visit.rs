@@ -0,0 +1,53 @@
+use syn::visit::Visit;
+
+use crate::{EnumBinding, FieldBinding, StructBinding, VariantBinding};
+
+impl FieldBinding {
+    /// Runs `visitor` over the declared type of this field.
+    pub fn visit_type<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) {
+        visitor.visit_type(self.ty());
+    }
+}
+
+impl StructBinding {
+    /// Runs `visitor` over the declared type of every field, in declaration order.
+    ///
+    /// Use this to search field types with `syn`'s traversal, such as for `Self` references
+    /// or the lifetimes they mention:
+    ///
+    /// ```ignore
+    /// struct Lifetimes<'ast>(Vec<&'ast Lifetime>);
+    ///
+    /// impl<'ast> Visit<'ast> for Lifetimes<'ast> {
+    ///     fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+    ///         self.0.push(lifetime);
+    ///     }
+    /// }
+    ///
+    /// let mut lifetimes = Lifetimes(Vec::new());
+    /// binding.visit_types(&mut lifetimes);
+    /// ```
+    pub fn visit_types<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) {
+        for fb in self.iter() {
+            fb.visit_type(visitor);
+        }
+    }
+}
+
+impl VariantBinding {
+    /// Runs `visitor` over the declared type of every field of this variant,
+    /// see [`StructBinding::visit_types`].
+    pub fn visit_types<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) {
+        self.struct_binding().visit_types(visitor);
+    }
+}
+
+impl EnumBinding {
+    /// Runs `visitor` over the declared type of every field of every variant,
+    /// see [`StructBinding::visit_types`].
+    pub fn visit_types<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) {
+        for vb in self.iter() {
+            vb.visit_types(visitor);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::Path,
+};
+
+use proc_macro2::TokenStream;
+
+use crate::ToPrettyString;
+
+/// Formats `tokens` with `prettyplease` and writes them to `path`.
+///
+/// Intended for build scripts that reuse binding logic to generate code into `OUT_DIR`:
+///
+/// ```ignore
+/// let file = syn::parse_file(&std::fs::read_to_string("src/ast.rs")?)?;
+/// let tokens = my_generator(&file);
+///
+/// let out = std::path::Path::new(&std::env::var("OUT_DIR")?).join("ast_gen.rs");
+/// hizli_core::emit_to_file(&out, tokens)?;
+/// ```
+///
+/// The output is deterministic for a given token stream, and the file is only rewritten
+/// when its contents change so downstream builds are not invalidated needlessly.
+///
+/// Returns an [`ErrorKind::InvalidData`] error if `tokens` is not a valid Rust file.
+pub fn emit_to_file(path: impl AsRef<Path>, tokens: TokenStream) -> Result<()> {
+    let path = path.as_ref();
+    let formatted = tokens
+        .to_pretty_string()
+        .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+
+    if fs::read_to_string(path).is_ok_and(|existing| existing == formatted) {
+        return Ok(());
+    }
+
+    fs::write(path, formatted)
+}
@@ -0,0 +1,27 @@
+use syn::{Error, Result};
+
+/// Collects every `Ok` value of `iter`, or every error combined into one.
+///
+/// Unlike collecting into `Result<Vec<T>>`, which stops at the first error, the whole iterator
+/// is consumed and the errors are combined in order with [`syn::Error::combine`], so a derive
+/// reports each invalid field or variant in one compiler run:
+///
+/// ```ignore
+/// let arms = collect_all(e.variants.iter().map(|variant| arm(variant, &fragments)))?;
+/// ```
+pub fn collect_all<T>(iter: impl IntoIterator<Item = Result<T>>) -> Result<Vec<T>> {
+    let mut values = Vec::new();
+    let mut errors: Option<Error> = None;
+    for item in iter {
+        match (item, &mut errors) {
+            (Ok(value), None) => values.push(value),
+            (Ok(_), Some(_)) => {}
+            (Err(err), Some(errors)) => errors.combine(err),
+            (Err(err), None) => errors = Some(err),
+        }
+    }
+    match errors {
+        None => Ok(values),
+        Some(errors) => Err(errors),
+    }
+}
@@ -0,0 +1,91 @@
+//! Conversions from the syntax trees of other `syn` major versions, enabled by the `syn1` and
+//! `syn3` features.
+//!
+//! | Module | Description |
+//! |---------|-------------|
+//! | `syn1` | Converts `syn` 1 nodes, for macros that have not migrated to `syn` 2 yet. |
+//! | `syn3` | Converts `syn` 3 nodes, for macros that already migrated past `syn` 2. |
+//!
+//! Every version shares `proc_macro2` and `quote`, so nodes are converted by printing them
+//! and parsing the tokens again, which keeps their spans. Each module re-exports its `syn`
+//! as `syn` and provides the same functions, so switching versions only changes the path:
+//!
+//! ```ignore
+//! use hizli::compat::syn1::{self, syn};
+//!
+//! let input: syn::DeriveInput = syn::parse(tokens)?;
+//! let input = syn1::derive_input(&input)?;
+//! ```
+
+use quote::ToTokens;
+use syn::{Result, parse::Parse};
+
+/// Converts any node that prints to tokens into the `syn` 2 node `T` by parsing them again.
+///
+/// Fails when the tokens do not parse as `T`, for example when the node is of a newer
+/// version that introduced syntax unknown to `syn` 2.
+pub fn convert<T: Parse>(node: &impl ToTokens) -> Result<T> {
+    syn::parse2(node.to_token_stream())
+}
+
+macro_rules! compat {
+    ($krate:ident) => {
+        pub use ::$krate as syn;
+
+        use $crate::{EnumBinding, StructBinding, VariantBinding, compat::convert};
+
+        /// Converts a [`DeriveInput`](syn::DeriveInput) into its `syn` 2 counterpart.
+        pub fn derive_input(input: &syn::DeriveInput) -> ::syn::Result<::syn::DeriveInput> {
+            convert(input)
+        }
+
+        /// Converts [`Fields`](syn::Fields) into their `syn` 2 counterpart.
+        pub fn fields(fields: &syn::Fields) -> ::syn::Result<::syn::Fields> {
+            Ok(match fields {
+                syn::Fields::Named(named) => ::syn::Fields::Named(convert(named)?),
+                syn::Fields::Unnamed(unnamed) => ::syn::Fields::Unnamed(convert(unnamed)?),
+                syn::Fields::Unit => ::syn::Fields::Unit,
+            })
+        }
+
+        /// Converts a [`Variant`](syn::Variant) into its `syn` 2 counterpart.
+        pub fn variant(variant: &syn::Variant) -> ::syn::Result<::syn::Variant> {
+            convert(variant)
+        }
+
+        /// Constructs a [`StructBinding`] from [`Fields`](syn::Fields),
+        /// like [`StructBinding::try_new`].
+        pub fn struct_binding(fields: &syn::Fields) -> ::syn::Result<StructBinding> {
+            StructBinding::try_new(&self::fields(fields)?)
+        }
+
+        /// Constructs a [`VariantBinding`] from a [`Variant`](syn::Variant),
+        /// like [`VariantBinding::try_new`].
+        pub fn variant_binding(variant: &syn::Variant) -> ::syn::Result<VariantBinding> {
+            VariantBinding::try_new(&self::variant(variant)?)
+        }
+
+        /// Constructs an [`EnumBinding`] from a [`DataEnum`](syn::DataEnum),
+        /// like [`EnumBinding::new`].
+        pub fn enum_binding(data: &syn::DataEnum) -> ::syn::Result<EnumBinding> {
+            Ok(EnumBinding::from_parts(
+                data.variants
+                    .iter()
+                    .map(variant_binding)
+                    .collect::<::syn::Result<_>>()?,
+            ))
+        }
+    };
+}
+
+/// Conversions from `syn` 1, enabled by the `syn1` feature.
+#[cfg(feature = "syn1")]
+pub mod syn1 {
+    compat!(syn1);
+}
+
+/// Conversions from `syn` 3, enabled by the `syn3` feature.
+#[cfg(feature = "syn3")]
+pub mod syn3 {
+    compat!(syn3);
+}
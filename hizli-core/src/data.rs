@@ -1,4 +1,7 @@
-use syn::{Data, DataEnum, DataStruct, Error, Result};
+use proc_macro2::Span;
+use syn::{Data, DataEnum, DataStruct, Error, Result, Variant};
+#[cfg(feature = "full")]
+use syn::{Item, ItemEnum, ItemStruct, spanned::Spanned};
 
 /// Represents a `syn::Data` node restricted to only `struct` or `enum` variants.
 ///
@@ -30,9 +33,46 @@ impl StructEnumOnly {
         match data {
             Data::Struct(s) => Ok(Self::Struct(s)),
             Data::Enum(e) => Ok(Self::Enum(e)),
-            Data::Union(u) => Err(Error::new(
+            union => Err(unsupported(&union, derive_name)),
+        }
+    }
+
+    /// Like [`StructEnumOnly::try_new`], but points the error at `span` instead of the data keyword,
+    /// typically [`derive_span`](`crate::derive_span`).
+    pub fn try_new_at(data: Data, derive_name: &str, span: Span) -> Result<Self> {
+        Self::try_new(data, derive_name).map_err(|err| respan(err, span))
+    }
+
+    /// Attempts to construct a [`StructEnumOnly`] from a [`syn::Item`] value.
+    ///
+    /// Intended for attribute and function-like macros that parse whole items.
+    ///
+    /// # Parameters
+    /// - `item`: The parsed item the macro was applied to.
+    /// - `macro_name`: The name of the current attribute macro, used in error messages.
+    ///
+    /// # Usage
+    ///
+    /// ```ignore
+    /// let item: Item = syn::parse(input)?;
+    ///
+    /// match StructEnumOnly::try_from_item(item, "my_macro")? {
+    ///     StructEnumOnly::Struct(s) => todo!(),
+    ///     StructEnumOnly::Enum(s) => todo!(),
+    /// }
+    /// ```
+    #[cfg(feature = "full")]
+    pub fn try_from_item(item: Item, macro_name: &str) -> Result<Self> {
+        match item {
+            Item::Struct(s) => Ok(Self::Struct(data_struct(s))),
+            Item::Enum(e) => Ok(Self::Enum(data_enum(e))),
+            Item::Union(u) => Err(Error::new(
                 u.union_token.span,
-                format!("Cannot #[derive({derive_name})] On Union"),
+                format!("Cannot #[{macro_name}] On Union"),
+            )),
+            other => Err(Error::new(
+                other.span(),
+                format!("#[{macro_name}] Can Only Be Applied To A Struct Or Enum"),
             )),
         }
     }
@@ -59,13 +99,43 @@ impl StructOnly {
     pub fn try_new(data: Data, derive_name: &str) -> Result<Self> {
         match data {
             Data::Struct(s) => Ok(Self(s)),
-            Data::Enum(e) => Err(Error::new(
+            other => Err(unsupported(&other, derive_name)),
+        }
+    }
+
+    /// Like [`StructOnly::try_new`], but points the error at `span` instead of the data keyword,
+    /// typically [`derive_span`](`crate::derive_span`).
+    pub fn try_new_at(data: Data, derive_name: &str, span: Span) -> Result<Self> {
+        Self::try_new(data, derive_name).map_err(|err| respan(err, span))
+    }
+
+    /// Attempts to construct a [`StructOnly`] from a [`syn::Item`] value.
+    ///
+    /// # Parameters
+    /// - `item`: The parsed item the macro was applied to.
+    /// - `macro_name`: The name of the current attribute macro, used in error diagnostics.
+    ///
+    ///  # Usage
+    /// ```ignore
+    /// let item: Item = syn::parse(input)?;
+    ///
+    /// let StructOnly(data) = StructOnly::try_from_item(item, "my_macro")?;
+    /// ```
+    #[cfg(feature = "full")]
+    pub fn try_from_item(item: Item, macro_name: &str) -> Result<Self> {
+        match item {
+            Item::Struct(s) => Ok(Self(data_struct(s))),
+            Item::Enum(e) => Err(Error::new(
                 e.enum_token.span,
-                format!("Cannot #[derive({derive_name})] On Enum"),
+                format!("Cannot #[{macro_name}] On Enum"),
             )),
-            Data::Union(u) => Err(Error::new(
+            Item::Union(u) => Err(Error::new(
                 u.union_token.span,
-                format!("Cannot #[derive({derive_name})] On Union"),
+                format!("Cannot #[{macro_name}] On Union"),
+            )),
+            other => Err(Error::new(
+                other.span(),
+                format!("#[{macro_name}] Can Only Be Applied To A Struct"),
             )),
         }
     }
@@ -92,14 +162,166 @@ impl EnumOnly {
     pub fn try_new(data: Data, derive_name: &str) -> Result<Self> {
         match data {
             Data::Enum(e) => Ok(Self(e)),
-            Data::Struct(s) => Err(Error::new(
+            other => Err(unsupported(&other, derive_name)),
+        }
+    }
+
+    /// Like [`EnumOnly::try_new`], but points the error at `span` instead of the data keyword,
+    /// typically [`derive_span`](`crate::derive_span`).
+    pub fn try_new_at(data: Data, derive_name: &str, span: Span) -> Result<Self> {
+        Self::try_new(data, derive_name).map_err(|err| respan(err, span))
+    }
+
+    /// Attempts to construct a [`EnumOnly`] from a [`syn::Item`] value.
+    ///
+    /// # Parameters
+    /// - `item`: The parsed item the macro was applied to.
+    /// - `macro_name`: The name of the current attribute macro, used in error diagnostics.
+    ///
+    ///  # Usage
+    /// ```ignore
+    /// let item: Item = syn::parse(input)?;
+    ///
+    /// let EnumOnly(data) = EnumOnly::try_from_item(item, "my_macro")?;
+    /// ```
+    #[cfg(feature = "full")]
+    pub fn try_from_item(item: Item, macro_name: &str) -> Result<Self> {
+        match item {
+            Item::Enum(e) => Ok(Self(data_enum(e))),
+            Item::Struct(s) => Err(Error::new(
                 s.struct_token.span,
-                format!("Cannot #[derive({derive_name})] On Struct"),
+                format!("Cannot #[{macro_name}] On Struct"),
             )),
-            Data::Union(u) => Err(Error::new(
+            Item::Union(u) => Err(Error::new(
                 u.union_token.span,
-                format!("Cannot #[derive({derive_name})] On Union"),
+                format!("Cannot #[{macro_name}] On Union"),
+            )),
+            other => Err(Error::new(
+                other.span(),
+                format!("#[{macro_name}] Can Only Be Applied To An Enum"),
+            )),
+        }
+    }
+}
+
+/// Wrapper around [`syn::DataEnum`] that rejects anything but an enum with exactly one variant.
+///
+/// Intended for derive macros that treat an enum as a wrapper around its only variant, such as
+/// transparent or newtype-like derives.
+pub struct SingleVariantEnumOnly(pub DataEnum);
+
+impl SingleVariantEnumOnly {
+    /// Attempts to construct a [`SingleVariantEnumOnly`] from a [`syn::Data`] value.
+    ///
+    /// Structs and unions are rejected like [`EnumOnly::try_new`] does. An enum without variants
+    /// is rejected at its `enum` keyword, and an enum with more than one variant at its second
+    /// variant.
+    ///
+    /// # Parameters
+    /// - `data`: The `syn::Data` node extracted from a parsed item.
+    /// - `derive_name`: The name of the current derive macro, used in error diagnostics.
+    ///
+    ///  # Usage
+    /// ```ignore
+    /// let input: DeriveInput = ...;
+    ///
+    /// let single = SingleVariantEnumOnly::try_new(input.data, "MyMacro")?;
+    /// let variant = single.variant();
+    /// ```
+    pub fn try_new(data: Data, derive_name: &str) -> Result<Self> {
+        let EnumOnly(e) = EnumOnly::try_new(data, derive_name)?;
+        Self::try_from_enum(e, &format!("#[derive({derive_name})]"))
+    }
+
+    /// Like [`SingleVariantEnumOnly::try_new`], but points the error at `span` instead of the
+    /// data keyword or variant, typically [`derive_span`](`crate::derive_span`).
+    pub fn try_new_at(data: Data, derive_name: &str, span: Span) -> Result<Self> {
+        Self::try_new(data, derive_name).map_err(|err| respan(err, span))
+    }
+
+    /// Attempts to construct a [`SingleVariantEnumOnly`] from a [`syn::Item`] value.
+    ///
+    /// # Parameters
+    /// - `item`: The parsed item the macro was applied to.
+    /// - `macro_name`: The name of the current attribute macro, used in error diagnostics.
+    ///
+    ///  # Usage
+    /// ```ignore
+    /// let item: Item = syn::parse(input)?;
+    ///
+    /// let single = SingleVariantEnumOnly::try_from_item(item, "my_macro")?;
+    /// ```
+    #[cfg(feature = "full")]
+    pub fn try_from_item(item: Item, macro_name: &str) -> Result<Self> {
+        let EnumOnly(e) = EnumOnly::try_from_item(item, macro_name)?;
+        Self::try_from_enum(e, &format!("#[{macro_name}]"))
+    }
+
+    /// Returns the only variant of the enum.
+    pub fn variant(&self) -> &Variant {
+        &self.0.variants[0]
+    }
+
+    fn try_from_enum(e: DataEnum, usage: &str) -> Result<Self> {
+        let mut variants = e.variants.iter();
+        match (variants.next(), variants.next()) {
+            (Some(_), None) => Ok(Self(e)),
+            (None, _) => Err(Error::new(
+                e.enum_token.span,
+                format!("Cannot {usage} On An Enum Without Variants"),
+            )),
+            (Some(_), Some(second)) => Err(Error::new(
+                second.ident.span(),
+                format!("{usage} Requires An Enum With Exactly One Variant"),
             )),
         }
     }
 }
+
+/// Moves every message of `err` to `span`, keeping them all.
+pub(crate) fn respan(err: Error, span: Span) -> Error {
+    err.into_iter()
+        .map(|err| Error::new(span, err))
+        .reduce(|mut combined, err| {
+            combined.combine(err);
+            combined
+        })
+        .expect("syn::Error holds at least one message")
+}
+
+/// Returns the error of a derive that does not apply to the kind of `data`, such as
+/// ``Cannot #[derive(MyMacro)] On Enum``, pointing at its `struct`, `enum` or `union` keyword.
+///
+/// Shared by the guards above and the `try_from_input` constructors of the binding types.
+pub(crate) fn unsupported(data: &Data, derive_name: &str) -> Error {
+    let (span, kind) = match data {
+        Data::Struct(s) => (s.struct_token.span, "Struct"),
+        Data::Enum(e) => (e.enum_token.span, "Enum"),
+        Data::Union(u) => (u.union_token.span, "Union"),
+    };
+    unsupported_kind(span, kind, derive_name)
+}
+
+/// Returns the error of [`unsupported`] for a `kind` of data known only by name, as for the
+/// bodies collected by darling, which keep no keyword to point at.
+pub(crate) fn unsupported_kind(span: Span, kind: &str, derive_name: &str) -> Error {
+    Error::new(span, format!("Cannot #[derive({derive_name})] On {kind}"))
+}
+
+#[cfg(feature = "full")]
+fn data_struct(item: ItemStruct) -> DataStruct {
+    DataStruct {
+        struct_token: item.struct_token,
+        fields: item.fields,
+        semi_token: item.semi_token,
+    }
+}
+
+#[cfg(feature = "full")]
+fn data_enum(item: ItemEnum) -> DataEnum {
+    DataEnum {
+        enum_token: item.enum_token,
+        brace_token: item.brace_token,
+        variants: item.variants,
+    }
+}
@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream;
+
+/// Dumps the expansion of the macro handler `name` when `HIZLI_EXPAND_DEBUG` is set,
+/// called by [`out!`](`crate::out`) for every expansion.
+///
+/// Requires the `debug` feature, otherwise this is a no-op:
+///
+/// - `HIZLI_EXPAND_DEBUG=1` (or `stderr`) prints the expansion to stderr.
+/// - Any other value is a file path the expansion is appended to.
+///
+/// The expansion is formatted with `prettyplease` when it is a valid Rust file.
+///
+/// ```text
+/// HIZLI_EXPAND_DEBUG=1 cargo build
+/// HIZLI_EXPAND_DEBUG=/tmp/expanded.rs cargo build
+/// ```
+pub fn dump_expansion(name: &str, tokens: &TokenStream) {
+    #[cfg(feature = "debug")]
+    {
+        use std::{env, fs::OpenOptions, io::Write};
+
+        use crate::ToPrettyString;
+
+        let target = match env::var("HIZLI_EXPAND_DEBUG") {
+            Ok(target) if !target.is_empty() => target,
+            _ => return,
+        };
+        let formatted = tokens
+            .to_pretty_string()
+            .unwrap_or_else(|_| tokens.to_string());
+        let dump = format!("// ---- {name} ----\n{formatted}\n");
+
+        match target.as_str() {
+            "1" | "stderr" => eprint!("{dump}"),
+            path => {
+                let written = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .and_then(|mut file| file.write_all(dump.as_bytes()));
+                if let Err(err) = written {
+                    eprintln!("Failed To Write `HIZLI_EXPAND_DEBUG` Dump To `{path}`: {err}");
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "debug"))]
+    let _ = (name, tokens);
+}
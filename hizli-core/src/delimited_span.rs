@@ -0,0 +1,103 @@
+use proc_macro2::{Delimiter, Group, Span, extra::DelimSpan};
+use syn::{
+    Error,
+    token::{Brace, Bracket, Paren},
+};
+
+use crate::Spanable;
+
+/// The spans of a delimited group: the whole group, its open delimiter and its close delimiter.
+///
+/// Built from the `syn` delimiter tokens or from a [`Group`], so manual `Parse` impls and the
+/// `#[hizli(delimited = ...)]` derives can keep where a group starts and ends without holding on
+/// to the token itself. A `DelimitedSpan` field of a `#[hizli(delimited = ...)]` struct or variant
+/// receives the spans of its delimiter.
+///
+/// Example:
+/// ```ignore
+/// let content;
+/// let delimited = DelimitedSpan::from(braced!(content in input));
+/// if content.is_empty() {
+///     return Err(Error::new(delimited.span(), "Empty Block"));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DelimitedSpan {
+    delimiter: Delimiter,
+    span: Span,
+    open: Span,
+    close: Span,
+}
+
+impl DelimitedSpan {
+    /// Creates a [`DelimitedSpan`] of a group delimited by `delimiter`.
+    pub fn new(delimiter: Delimiter, span: &DelimSpan) -> Self {
+        Self {
+            delimiter,
+            span: span.join(),
+            open: span.open(),
+            close: span.close(),
+        }
+    }
+
+    /// Returns the delimiter of the group.
+    pub fn delimiter(&self) -> Delimiter {
+        self.delimiter
+    }
+
+    /// Returns the span of the whole group, delimiters included.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the span of the open delimiter.
+    pub fn open(&self) -> Span {
+        self.open
+    }
+
+    /// Returns the span of the close delimiter.
+    pub fn close(&self) -> Span {
+        self.close
+    }
+
+    /// Returns an error at the open delimiter reporting that the group is never closed.
+    pub fn error_unclosed(&self) -> Error {
+        let open = match self.delimiter {
+            Delimiter::Brace => "{",
+            Delimiter::Parenthesis => "(",
+            Delimiter::Bracket => "[",
+            Delimiter::None => return Error::new(self.open, "Unclosed Group"),
+        };
+        Error::new(self.open, format!("Unclosed Delimiter `{open}`"))
+    }
+}
+
+impl From<Brace> for DelimitedSpan {
+    fn from(token: Brace) -> Self {
+        Self::new(Delimiter::Brace, &token.span)
+    }
+}
+
+impl From<Paren> for DelimitedSpan {
+    fn from(token: Paren) -> Self {
+        Self::new(Delimiter::Parenthesis, &token.span)
+    }
+}
+
+impl From<Bracket> for DelimitedSpan {
+    fn from(token: Bracket) -> Self {
+        Self::new(Delimiter::Bracket, &token.span)
+    }
+}
+
+impl From<&Group> for DelimitedSpan {
+    fn from(group: &Group) -> Self {
+        Self::new(group.delimiter(), &group.delim_span())
+    }
+}
+
+impl Spanable for DelimitedSpan {
+    fn spanable(&self) -> Span {
+        self.span
+    }
+}
@@ -0,0 +1,15 @@
+use proc_macro2::Span;
+
+/// Returns the span errors about a derive as a whole point at: [`Span::call_site`].
+///
+/// rustc strips the `#[derive(...)]` attribute invoking a derive macro from its input, so the
+/// path cannot be found in it. Inside a derive macro, the call site is that same path, which is
+/// where users look to remove or fix a derive that does not apply.
+///
+/// # Usage
+/// ```ignore
+/// let StructOnly(data) = StructOnly::try_new_at(input.data, "MyMacro", derive_span())?;
+/// ```
+pub fn derive_span() -> Span {
+    Span::call_site()
+}
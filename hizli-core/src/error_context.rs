@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use proc_macro2::Span;
+use syn::{Error, Result};
+
+/// Prefixes every message of `err` with `context`, keeping the span of each message.
+///
+/// Used by `#[derive(Parse)]` with `#[hizli(context)]` to report which field failed, so the
+/// errors of nested grammars read outermost production first:
+///
+/// ```text
+/// While Parsing Field `body` Of `Fn`: While Parsing Field `0` Of `Stmt::Let`: expected `=`
+/// ```
+pub fn error_context(err: Error, context: impl Display) -> Error {
+    err.into_iter()
+        .map(|err| Error::new(err.span(), format!("{context}: {err}")))
+        .reduce(|mut combined, err| {
+            combined.combine(err);
+            combined
+        })
+        .expect("syn::Error holds at least one message")
+}
+
+/// Layers diagnostics onto a `syn::Result`, see [`ErrorExt::with_context`].
+pub trait ErrorExt<T> {
+    /// Wraps the error, if any, in a combined error starting with `context` at `span`, followed by
+    /// every message of the inner error with its own span.
+    ///
+    /// Lets handlers say what they were expanding when a nested step failed, the same way across
+    /// hizli-based macros:
+    ///
+    /// ```ignore
+    /// let binding = StructBinding::try_new(&s.fields)
+    ///     .with_context(ident.span(), format!("While Expanding #[derive(Parse)] For `{ident}`"))?;
+    /// ```
+    fn with_context(self, span: Span, context: impl Display) -> Result<T>;
+}
+
+impl<T> ErrorExt<T> for Result<T> {
+    fn with_context(self, span: Span, context: impl Display) -> Result<T> {
+        self.map_err(|err| {
+            let mut combined = Error::new(span, context);
+            combined.combine(err);
+            combined
+        })
+    }
+}
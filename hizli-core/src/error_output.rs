@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use syn::Error;
+
+/// Converts the error of a macro handler into the macro output, used by [`out!`](`crate::out`).
+///
+/// This is [`Error::into_compile_error`], whatever features are enabled, so it is safe in any
+/// macro entry point. See `emit_error_output`, with the `proc-macro-error` feature, for emitting
+/// through `proc_macro_error2`.
+pub fn error_output(err: Error) -> TokenStream {
+    err.into_compile_error()
+}
+
+/// Like [`error_output`], but emits every combined error through `proc_macro_error2`, which
+/// renders multiple spanned errors together, and returns an empty stream. Enabled by the
+/// `proc-macro-error` feature and used by `out!(handler, input, emit)`.
+///
+/// Emitting panics outside of `#[proc_macro_error]`, so only entry points annotated with it,
+/// re-exported as `proc_macro_error2::proc_macro_error`, may opt in. Outside of a procedural
+/// macro, it falls back to [`Error::into_compile_error`].
+///
+/// ```ignore
+/// #[proc_macro_derive(MyDerive)]
+/// #[hizli::proc_macro_error2::proc_macro_error]
+/// pub fn my_derive(input: TokenStream) -> TokenStream {
+///     out!(handler, input, emit)
+/// }
+/// ```
+#[cfg(feature = "proc-macro-error")]
+pub fn emit_error_output(err: Error) -> TokenStream {
+    if proc_macro::is_available() {
+        for err in err {
+            proc_macro_error2::Diagnostic::from(err).emit();
+        }
+        return TokenStream::new();
+    }
+
+    err.into_compile_error()
+}
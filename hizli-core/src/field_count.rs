@@ -0,0 +1,67 @@
+use std::ops::RangeInclusive;
+
+use proc_macro2::Span;
+use syn::{Error, Fields, Result, spanned::Spanned};
+
+/// Checks that `fields` has exactly `expected` fields, for derives constrained to newtypes, pairs
+/// and the like.
+///
+/// An extra field is reported at the first field past `expected`, a missing one at the closing
+/// delimiter of the fields, or at [`Span::call_site`] for a unit struct or variant.
+///
+/// # Usage
+/// ```ignore
+/// let StructOnly(data) = StructOnly::try_new(input.data, "Newtype")?;
+/// require_field_count(&data.fields, 1, "Newtype")?;
+/// ```
+pub fn require_field_count(fields: &Fields, expected: usize, derive_name: &str) -> Result<()> {
+    let requirement = format!("Exactly {}", count(expected));
+    check(fields, expected..=expected, derive_name, &requirement)
+}
+
+/// Checks that `fields` has at least `min` fields, reporting a missing field like
+/// [`require_field_count`].
+pub fn require_field_count_at_least(fields: &Fields, min: usize, derive_name: &str) -> Result<()> {
+    let requirement = format!("At Least {}", count(min));
+    check(fields, min..=usize::MAX, derive_name, &requirement)
+}
+
+/// Checks that `fields` has at most `max` fields, reporting an extra field like
+/// [`require_field_count`].
+pub fn require_field_count_at_most(fields: &Fields, max: usize, derive_name: &str) -> Result<()> {
+    let requirement = format!("At Most {}", count(max));
+    check(fields, 0..=max, derive_name, &requirement)
+}
+
+fn count(n: usize) -> String {
+    match n {
+        1 => "1 Field".to_string(),
+        n => format!("{n} Fields"),
+    }
+}
+
+fn check(
+    fields: &Fields,
+    allowed: RangeInclusive<usize>,
+    derive_name: &str,
+    requirement: &str,
+) -> Result<()> {
+    let span = match fields.iter().nth(*allowed.end()) {
+        Some(extra) => extra.span(),
+        None if fields.len() < *allowed.start() => missing(fields),
+        None => return Ok(()),
+    };
+    Err(Error::new(
+        span,
+        format!("#[derive({derive_name})] Requires {requirement}"),
+    ))
+}
+
+/// Returns where a missing field would go: the closing delimiter of `fields`.
+fn missing(fields: &Fields) -> Span {
+    match fields {
+        Fields::Named(named) => named.brace_token.span.close(),
+        Fields::Unnamed(unnamed) => unnamed.paren_token.span.close(),
+        Fields::Unit => Span::call_site(),
+    }
+}
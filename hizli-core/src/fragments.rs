@@ -0,0 +1,112 @@
+use std::cell::OnceCell;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+macro_rules! fragments {
+    ($root: ident; $($(#[$doc: meta])* $name: ident => { $($tokens: tt)* })*) => {
+        /// Token fragments built once per expansion and cloned wherever they are interpolated.
+        ///
+        /// Generated code repeats the same absolute paths for every field and variant, such as
+        /// `::core::result::Result::Ok`. Quoting them once and cloning the stream avoids
+        /// rebuilding them, which shows on large inputs.
+        ///
+        /// Create one [`Fragments`] per expansion and pass it down to the code generating each
+        /// field or variant. It is deliberately not a `static`: the tokens of a procedural macro
+        /// are only valid during the invocation that created them.
+        ///
+        /// Paths into the standard library start at `::core`, and `Vec` at `::std`, unless
+        /// [`Fragments::with_root`] names another root, such as `::std` or a facade crate
+        /// re-exporting it, or [`Fragments::with_alloc`] names the crate providing `Vec`.
+        ///
+        /// # Usage
+        /// ```ignore
+        /// let fragments = Fragments::new();
+        /// let ok = fragments.ok();
+        ///
+        /// quote! { fn parse(input: ParseStream) -> syn::Result<Self> { #ok(Self) } }
+        /// ```
+        #[derive(Default)]
+        pub struct Fragments {
+            root: Option<TokenStream>,
+            alloc: Option<TokenStream>,
+            $($name: OnceCell<TokenStream>,)*
+        }
+
+        impl Fragments {
+            $(
+                $(#[$doc])*
+                pub fn $name(&self) -> TokenStream {
+                    self.$name
+                        .get_or_init(|| {
+                            let $root = self.root();
+                            quote! { $($tokens)* }
+                        })
+                        .clone()
+                }
+            )*
+        }
+    };
+}
+
+fragments! {
+    root;
+    /// Returns `::core::result::Result::Ok`.
+    ok => { #root::result::Result::Ok }
+    /// Returns `::core::result::Result::Err`.
+    err => { #root::result::Result::Err }
+    /// Returns `::core::option::Option::Some`.
+    some => { #root::option::Option::Some }
+    /// Returns `::core::option::Option::None`.
+    none => { #root::option::Option::None }
+    /// Returns `::core::default::Default::default()`.
+    default_expr => { #root::default::Default::default() }
+    /// Returns `::core::clone::Clone::clone`.
+    clone_path => { #root::clone::Clone::clone }
+    /// Returns `::core::convert::Into::into`.
+    into_path => { #root::convert::Into::into }
+}
+
+impl Fragments {
+    /// Creates an empty [`Fragments`], building each fragment on first use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty [`Fragments`] whose paths into the standard library start at `root`
+    /// instead of `::core`, as set by `#[hizli(root = ::std)]`.
+    pub fn with_root(root: TokenStream) -> Self {
+        Self {
+            root: Some(root),
+            ..Self::default()
+        }
+    }
+
+    /// Reaches `Vec` through `alloc` instead of the root, as set by `#[hizli(alloc = ::alloc)]`.
+    ///
+    /// Use this with a root that has no `vec` module, such as `::core`.
+    pub fn with_alloc(mut self, alloc: TokenStream) -> Self {
+        self.alloc = Some(alloc);
+        self
+    }
+
+    /// Returns the root of paths into the standard library, `::core` by default.
+    pub fn root(&self) -> TokenStream {
+        match &self.root {
+            Some(root) => root.clone(),
+            None => quote! { ::core },
+        }
+    }
+
+    /// Returns the path to `Vec`, `::std::vec::Vec` by default, since `::core` has none.
+    ///
+    /// With [`Fragments::with_alloc`] `Vec` is reached through that crate, otherwise through the
+    /// root set by [`Fragments::with_root`].
+    pub fn vec(&self) -> TokenStream {
+        match (&self.alloc, &self.root) {
+            (Some(alloc), _) => quote! { #alloc::vec::Vec },
+            (None, Some(root)) => quote! { #root::vec::Vec },
+            (None, None) => quote! { ::std::vec::Vec },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{GenericParam, Generics, Ident, TypeParamBound};
+
+/// Returns a copy of `generics` where every type parameter is bounded by `bound`.
+///
+/// Lifetime and const parameters are carried through untouched, so
+/// `struct Foo<'a, T, const N: usize>` bounded by `Clone` yields:
+///
+/// ```text
+/// <'a, T: Clone, const N: usize>
+/// ```
+pub fn bound_type_params(generics: &Generics, bound: &TypeParamBound) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.params.iter_mut() {
+        if let GenericParam::Type(ty) = param {
+            ty.bounds.push(bound.clone());
+        }
+    }
+    generics
+}
+
+/// Generates an impl block for `ident` with its own generic parameters.
+///
+/// Passing a `trait_path` implements that trait, otherwise an inherent impl is produced.
+/// Every kind of generic parameter, including `const N: usize`, is declared and applied:
+///
+/// ```text
+/// impl<'a, T: Clone, const N: usize> Trait for Foo<'a, T, N> where T: Default {
+///     #body
+/// }
+/// ```
+pub fn impl_block(
+    ident: &Ident,
+    generics: &Generics,
+    trait_path: Option<impl ToTokens>,
+    body: TokenStream,
+) -> TokenStream {
+    let (impl_gen, type_gen, where_cl) = generics.split_for_impl();
+    let trait_path = trait_path.map(|path| quote! { #path for });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_gen #trait_path #ident #type_gen #where_cl {
+            #body
+        }
+    }
+}
+
+/// Wraps `tokens` in an anonymous constant, hiding every item they declare from the caller's namespace.
+///
+/// ```text
+/// const _: () = {
+///     #tokens
+/// };
+/// ```
+///
+/// Impls inside the scope still apply globally, so helper types, imports
+/// and assertions can be emitted alongside them without polluting the user's module.
+pub fn anon_const_scope(tokens: TokenStream) -> TokenStream {
+    quote! {
+        const _: () = {
+            #tokens
+        };
+    }
+}
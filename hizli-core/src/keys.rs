@@ -0,0 +1,286 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{Error, Expr, Ident, Lit, Meta, MetaNameValue, Result, Token, parse::ParseStream};
+
+use crate::warning;
+
+/// Builds the error for an unrecognized attribute key, suggesting the closest known key
+/// and listing every accepted one.
+///
+/// The suggestion is the entry of `known` with the smallest edit distance to `key`,
+/// offered only when the distance is small enough to plausibly be a typo:
+///
+/// ```text
+/// Unknown Key `skp`, Did You Mean `skip`? (Accepted Keys: `skip`, `rename`)
+/// ```
+///
+/// Example:
+/// ```ignore
+/// match key.to_string().as_str() {
+///     "skip" => { /* ... */ }
+///     "rename" => { /* ... */ }
+///     _ => return Err(unknown_key(&key, &["skip", "rename"])),
+/// }
+/// ```
+pub fn unknown_key(key: &Ident, known: &[&str]) -> Error {
+    let key_str = key.to_string();
+    let closest = known
+        .iter()
+        .map(|candidate| (edit_distance(&key_str, candidate), candidate))
+        .filter(|(distance, _)| *distance <= (key_str.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance);
+
+    let message = match closest {
+        Some((_, candidate)) => format!("Unknown Key `{key_str}`, Did You Mean `{candidate}`?"),
+        None => format!("Unknown Key `{key_str}`"),
+    };
+    Error::new(key.span(), message + &accepted_keys(known))
+}
+
+/// Formats the ` (Accepted Keys: ...)` suffix of error messages, empty when `known` is.
+pub(crate) fn accepted_keys(known: &[&str]) -> String {
+    match known.is_empty() {
+        true => String::new(),
+        false => {
+            let known = known
+                .iter()
+                .map(|key| format!("`{key}`"))
+                .collect::<Vec<_>>();
+            format!(" (Accepted Keys: {})", known.join(", "))
+        }
+    }
+}
+
+/// Builds the error for a key whose value has the wrong form, spanned exactly at `value`
+/// rather than at the whole attribute.
+///
+/// `expected` describes the accepted form, and the found form is derived from `value`:
+///
+/// ```text
+/// Key `rename` Expected Identifier, Found String Literal
+/// ```
+///
+/// Example:
+/// ```ignore
+/// let value: Expr = input.parse()?;
+/// match &value {
+///     Expr::Path(path) if path.path.get_ident().is_some() => { /* ... */ }
+///     _ => return Err(value_error(&key, &value, "Identifier")),
+/// }
+/// ```
+pub fn value_error(key: &Ident, value: &Expr, expected: &str) -> Error {
+    Error::new_spanned(
+        value,
+        format!("Key `{key}` Expected {expected}, Found {}", describe(value)),
+    )
+}
+
+/// Describes the form of an attribute value for error messages.
+fn describe(value: &Expr) -> &'static str {
+    match value {
+        Expr::Lit(lit) => match lit.lit {
+            Lit::Str(_) => "String Literal",
+            Lit::ByteStr(_) | Lit::CStr(_) => "Byte String Literal",
+            Lit::Byte(_) => "Byte Literal",
+            Lit::Int(_) => "Integer Literal",
+            Lit::Char(_) => "Character Literal",
+            Lit::Float(_) => "Float Literal",
+            Lit::Bool(_) => "Boolean Literal",
+            _ => "Literal",
+        },
+        Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => "Identifier",
+        Expr::Path(_) => "Path",
+        Expr::Array(_) => "Array",
+        Expr::Tuple(_) => "Tuple",
+        Expr::Call(_) | Expr::MethodCall(_) => "Call",
+        Expr::Group(group) => describe(&group.expr),
+        _ => "Expression",
+    }
+}
+
+/// Builds the warning for a deprecated attribute key that is still accepted as `replacement`.
+///
+/// ```text
+/// Key `rename_all` Is Deprecated, Use `case` Instead
+/// ```
+///
+/// As with [`warning`](`crate::warning()`), the returned tokens must be included in the generated output.
+///
+/// Example:
+/// ```ignore
+/// let key = match key.to_string().as_str() {
+///     "rename_all" => {
+///         warnings.extend(deprecated_key(&key, "case"));
+///         Ident::new("case", key.span())
+///     }
+///     _ => key,
+/// };
+/// ```
+pub fn deprecated_key(key: &Ident, replacement: &str) -> TokenStream {
+    warning(
+        key.span(),
+        &format!("Key `{key}` Is Deprecated, Use `{replacement}` Instead"),
+    )
+}
+
+/// Skips the value of an attribute key, up to the next top-level `,` or the end of `input`.
+///
+/// Accepts any form following the key (`key`, `key = value`, `key(...)`), which lets lenient
+/// parsers ignore keys they do not recognize, for example keys added by newer versions of a macro.
+///
+/// Example:
+/// ```ignore
+/// match key.to_string().as_str() {
+///     "skip" => { /* ... */ }
+///     _ => skip_value(input)?,
+/// }
+/// ```
+pub fn skip_value(input: ParseStream) -> Result<()> {
+    value_tokens(input).map(|_| ())
+}
+
+/// Parses the remainder of an attribute argument whose `key` was already parsed as raw [`Meta`],
+/// up to the next top-level `,` or the end of `input`.
+///
+/// Lets lenient parsers preserve unknown keys so they can be forwarded verbatim to another macro.
+/// A value that is not a valid expression, such as `key = Vec<u8>`, is kept as [`Expr::Verbatim`].
+///
+/// Example:
+/// ```ignore
+/// match key.to_string().as_str() {
+///     "skip" => { /* ... */ }
+///     _ => forwarded.push(raw_meta(key, input)?),
+/// }
+/// quote! { #[serde(#(#forwarded),*)] }
+/// ```
+pub fn raw_meta(key: Ident, input: ParseStream) -> Result<Meta> {
+    let span = input.span();
+    let rest = value_tokens(input)?;
+    if let Ok(meta) = syn::parse2(quote! { #key #rest }) {
+        return Ok(meta);
+    }
+
+    let mut rest = rest.into_iter();
+    match rest.next() {
+        Some(TokenTree::Punct(eq)) if eq.as_char() == '=' => Ok(Meta::NameValue(MetaNameValue {
+            path: key.into(),
+            eq_token: Token![=](eq.span()),
+            value: Expr::Verbatim(rest.collect()),
+        })),
+        _ => Err(Error::new(
+            span,
+            "Expected `key`, `key = value` Or `key(...)`",
+        )),
+    }
+}
+
+/// Takes the tokens up to the next top-level `,` or the end of `input`.
+fn value_tokens(input: ParseStream) -> Result<TokenStream> {
+    input.step(|cursor| {
+        let mut tokens = TokenStream::new();
+        let mut rest = *cursor;
+        while let Some((tt, next)) = rest.token_tree() {
+            if let TokenTree::Punct(punct) = &tt
+                && punct.as_char() == ','
+            {
+                break;
+            }
+            tokens.extend([tt]);
+            rest = next;
+        }
+        Ok((tokens, rest))
+    })
+}
+
+/// Tracks the keys already seen while parsing attribute arguments.
+///
+/// Intended for [`NsAttr`](`crate::NsAttr`) [`Parse`](`syn::parse::Parse`) implementations
+/// so that repeated keys are reported instead of silently letting the last one win.
+///
+/// Example:
+/// ```ignore
+/// let mut keys = KeySet::default();
+/// while !input.is_empty() {
+///     let key: Ident = input.parse()?;
+///     keys.claim(&key)?;
+///     // ...
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct KeySet {
+    claimed: Vec<Ident>,
+}
+
+impl KeySet {
+    /// Records `key`, returning an error spanned at `key` if it was already claimed.
+    ///
+    /// ```text
+    /// Duplicate Key `skip`
+    /// ```
+    pub fn claim(&mut self, key: &Ident) -> Result<()> {
+        if self.claimed.contains(key) {
+            return Err(Error::new(key.span(), format!("Duplicate Key `{key}`")));
+        }
+        self.claimed.push(key.clone());
+        Ok(())
+    }
+
+    /// Returns `true` if `key` was already claimed.
+    pub fn contains(&self, key: &str) -> bool {
+        self.claimed.iter().any(|claimed| claimed == key)
+    }
+
+    /// Checks that every key of `required` was claimed, reporting all missing keys
+    /// in a single error spanned at `span`, typically the attribute or its argument list.
+    ///
+    /// ```text
+    /// Missing Required Keys `name`, `ty`
+    /// ```
+    ///
+    /// Example:
+    /// ```ignore
+    /// // after the parse loop, `input.span()` points at the argument list
+    /// keys.require(input.span(), &["name", "ty"])?;
+    /// ```
+    pub fn require(&self, span: Span, required: &[&str]) -> Result<()> {
+        let missing = required
+            .iter()
+            .filter(|key| !self.contains(key))
+            .map(|key| format!("`{key}`"))
+            .collect::<Vec<_>>();
+        match missing.len() {
+            0 => Ok(()),
+            1 => Err(Error::new(
+                span,
+                format!("Missing Required Key {}", missing[0]),
+            )),
+            _ => Err(Error::new(
+                span,
+                format!("Missing Required Keys {}", missing.join(", ")),
+            )),
+        }
+    }
+
+    /// Returns the claimed keys, in the order they were claimed.
+    pub fn claimed(&self) -> &[Ident] {
+        &self.claimed
+    }
+}
+
+/// Levenshtein distance between two strings, counted in `char`s.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
@@ -0,0 +1,108 @@
+use syn::{Error, Result};
+
+use crate::{AttrLevel, KeySet};
+
+/// A constraint between attribute keys declared at different [`AttrLevel`]s,
+/// checked by [`check_level_rules`].
+///
+/// Example:
+/// ```ignore
+/// const RULES: &[LevelRule] = &[
+///     LevelRule::NotBoth {
+///         key: "rename",
+///         levels: [AttrLevel::Type, AttrLevel::Field],
+///     },
+///     LevelRule::Requires {
+///         key: "skip",
+///         level: AttrLevel::Field,
+///         requires: "partial",
+///         at: AttrLevel::Type,
+///     },
+/// ];
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LevelRule {
+    /// `key` may not appear at both `levels`.
+    NotBoth {
+        key: &'static str,
+        levels: [AttrLevel; 2],
+    },
+    /// `key` at `level` requires the key `requires` at the level `at`.
+    Requires {
+        key: &'static str,
+        level: AttrLevel,
+        requires: &'static str,
+        at: AttrLevel,
+    },
+}
+
+/// Checks `rules` against the keys claimed at each level, combining every violation into one error.
+///
+/// `present` lists the [`KeySet`] of each parsed attribute with its level, typically one
+/// entry for the container and one per variant or field. Errors are spanned at the offending key:
+///
+/// ```text
+/// Key `rename` May Not Appear At Both The Type And Field Level
+/// Key `skip` At The Field Level Requires `partial` At The Type Level
+/// ```
+///
+/// Example:
+/// ```ignore
+/// let mut present = vec![(AttrLevel::Type, &container.keys)];
+/// present.extend(fields.iter().map(|field| (AttrLevel::Field, &field.keys)));
+/// check_level_rules(RULES, &present)?;
+/// ```
+pub fn check_level_rules(rules: &[LevelRule], present: &[(AttrLevel, &KeySet)]) -> Result<()> {
+    let claimed_at = |key: &'static str, level: AttrLevel| {
+        present
+            .iter()
+            .filter(move |(at, _)| *at == level)
+            .filter_map(move |(_, keys)| keys.claimed().iter().find(|claimed| *claimed == key))
+    };
+
+    let mut errors = Vec::new();
+    for rule in rules {
+        match *rule {
+            LevelRule::NotBoth {
+                key,
+                levels: [first, second],
+            } => {
+                if claimed_at(key, first).next().is_none() {
+                    continue;
+                }
+                errors.extend(claimed_at(key, second).map(|claimed| {
+                    Error::new(
+                        claimed.span(),
+                        format!(
+                            "Key `{key}` May Not Appear At Both The {first:?} And {second:?} Level"
+                        ),
+                    )
+                }));
+            }
+            LevelRule::Requires {
+                key,
+                level,
+                requires,
+                at,
+            } => {
+                if claimed_at(requires, at).next().is_some() {
+                    continue;
+                }
+                errors.extend(claimed_at(key, level).map(|claimed| {
+                    Error::new(
+                        claimed.span(),
+                        format!("Key `{key}` At The {level:?} Level Requires `{requires}` At The {at:?} Level"),
+                    )
+                }));
+            }
+        }
+    }
+
+    match errors.into_iter().reduce(|mut all, err| {
+        all.combine(err);
+        all
+    }) {
+        None => Ok(()),
+        Some(err) => Err(err),
+    }
+}
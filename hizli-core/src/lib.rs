@@ -72,6 +72,33 @@
 //! | `variant_binding` | Wraps enum variants into [`VariantBinding`] for uniform field access. |
 //! | `variant_pattern` | Provides `VariantBinding::variant_pattern()` for generating binding patterns. |
 //!
+//! ## Features
+//!
+//! By default only `syn`'s derive-level syntax tree is required, which keeps compile times low
+//! for plain derive macros.
+//!
+//! | Feature | Description |
+//! |---------|-------------|
+//! | `full` | Enables `syn/full` and the item-level APIs: `from_item_*` constructors, `SignatureBinding`, `ImplBlockBinding`, `TraitBinding` and file scanning. |
+//! | `build` | Adds `emit_to_file` for build scripts (implies `pretty`). |
+//! | `testing` | Adds the `testing` module, `assert_expands!`, `assert_parses!` and `assert_parse_err!` (implies `pretty`). |
+//! | `debug` | Lets `out!` dump expansions through `HIZLI_EXPAND_DEBUG` (implies `pretty`). |
+//! | `nightly` | Emits warnings through `proc_macro::Diagnostic`. |
+//! | `proc-macro-error` | Adds `emit_error_output`, emitting errors through `proc-macro-error2` from entry points that opt in with `out!(handler, input, emit)`. |
+//! | `syn1`, `syn3` | Adds the `compat` module, building bindings from other `syn` versions. |
+//! | `extra-traits` | Implements `Debug`, `PartialEq` and `Eq` for the binding types through `syn/extra-traits`. |
+//! | `darling` | Converts between the binding types and `darling::ast::{Data, Fields}`. |
+//! | `visit` | Runs `syn::visit::Visit` visitors over the field types of the binding types, and enables `TypeParamUsage` and `mentions_self`. |
+//! | `pretty` | Adds `ToPrettyString`, formatting generated code with `prettyplease` (implies `full`). |
+//! | `span-locations` | Adds `span_location`, `span_excerpt` and `render_error` for diagnostics printed outside of rustc, through `proc-macro2/span-locations`. |
+//!
+//! ## Determinism
+//!
+//! Generated code follows the declaration order of the input: bindings, match arms built from
+//! them, expected-one-of lists, `NsAttr` keys and where predicates. No output is produced by
+//! iterating a hash map, so expansions are identical across runs, keeping snapshot tests and
+//! build caches stable.
+//!
 //! ## Intended Use
 //!
 //! Designed for derive macro and codegen authors who need minimal, zero-dependency
@@ -79,12 +106,95 @@
 //! without repetitive boilerplate. The API is purely structural and does not depend
 //! on specific derive semantics, making it a general-purpose tool for code generation
 //! pipelines.
+//!
+//! Everything except the [`out!`] entry point is built on `proc_macro2` alone, so bindings,
+//! patterns and [`NsAttr`] parsing work in ordinary `cargo test` without a proc-macro host.
+//! The `nightly` and `proc-macro-error` features only reach for `proc_macro` after checking
+//! `proc_macro::is_available()`, and fall back to plain tokens otherwise.
+
+#![cfg_attr(feature = "nightly", feature(proc_macro_diagnostic))]
 
+#[cfg(any(feature = "nightly", feature = "proc-macro-error"))]
+extern crate proc_macro;
+
+mod attr_value;
 mod bindings;
+#[cfg(feature = "build")]
+mod codegen;
+mod collect_all;
+#[cfg(any(feature = "syn1", feature = "syn3"))]
+pub mod compat;
 mod data;
+mod debug;
+mod delimited_span;
+mod derive_span;
+mod error_context;
+mod error_output;
+mod field_count;
+mod fragments;
+mod impl_gen;
+mod keys;
+mod level_rules;
 mod ns_attr;
+mod paths;
+#[cfg(feature = "pretty")]
+mod pretty;
+#[doc(hidden)]
+pub mod private;
 mod rules;
+#[cfg(feature = "full")]
+mod scan;
+mod separated;
+#[cfg(feature = "span-locations")]
+mod span_display;
+mod spanable;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod warning;
+
+pub use attr_value::AttrValue;
+pub use bindings::{
+    AccessMode, EnumBinding, FieldBinding, FieldType, StructBinding, UnionBinding, VariantBinding,
+    assert_impl, ident_for, member_to_ident,
+};
+#[cfg(feature = "full")]
+pub use bindings::{ImplBlockBinding, SignatureBinding, TraitBinding, TraitMethodBinding};
+#[cfg(feature = "visit")]
+pub use bindings::{TypeParamUsage, mentions_self};
+#[cfg(feature = "build")]
+pub use codegen::emit_to_file;
+pub use collect_all::collect_all;
+pub use data::{EnumOnly, SingleVariantEnumOnly, StructEnumOnly, StructOnly};
+pub use debug::dump_expansion;
+pub use delimited_span::DelimitedSpan;
+pub use derive_span::derive_span;
+pub use error_context::{ErrorExt, error_context};
+#[cfg(feature = "proc-macro-error")]
+pub use error_output::emit_error_output;
+pub use error_output::error_output;
+pub use field_count::{
+    require_field_count, require_field_count_at_least, require_field_count_at_most,
+};
+pub use fragments::Fragments;
+pub use impl_gen::{anon_const_scope, bound_type_params, impl_block};
+pub use keys::{KeySet, deprecated_key, raw_meta, skip_value, unknown_key, value_error};
+pub use level_rules::{LevelRule, check_level_rules};
+pub use ns_attr::{AttrLevel, NsAttr, others};
+pub use paths::{qualified_path, turbofish, type_path};
+#[cfg(feature = "pretty")]
+pub use pretty::ToPrettyString;
+#[cfg(feature = "full")]
+pub use scan::{ItemBinding, ScannedItem, scan_file};
+pub use separated::{Separated, parse_separator};
+#[cfg(feature = "span-locations")]
+pub use span_display::{render_error, span_excerpt, span_location};
+pub use spanable::{Spanable, join_spans, span_or};
+pub use warning::warning;
 
-pub use bindings::{FieldBinding, FieldType, StructBinding, VariantBinding};
-pub use data::{EnumOnly, StructEnumOnly, StructOnly};
-pub use ns_attr::{AttrLevel, NsAttr};
+#[cfg(feature = "darling")]
+pub use darling;
+#[cfg(feature = "proc-macro-error")]
+pub use proc_macro_error2;
+pub use proc_macro2;
+pub use quote;
+pub use syn;
@@ -1,5 +1,11 @@
-use proc_macro2::Span;
-use syn::{Attribute, Error, Result, parse::Parse, spanned::Spanned};
+use proc_macro2::{Span, TokenStream};
+use syn::{
+    Attribute, Error, Expr, Fields, Meta, Path, Result,
+    parse::{Parse, ParseStream, Parser},
+    spanned::Spanned,
+};
+
+use crate::{FieldBinding, keys::accepted_keys};
 
 /// Indicates the syntactic level an attribute applies to.
 ///
@@ -7,11 +13,35 @@ use syn::{Attribute, Error, Result, parse::Parse, spanned::Spanned};
 /// - `Type` for attributes applied at the struct/enum level.  
 /// - `Variant` for attributes applied to enum variants.  
 /// - `Field` for attributes applied to struct fields.
-#[derive(Clone, Copy, Debug)]
+/// - `Item` for attributes applied to other free items, such as consts or statics.
+/// - `Fn` for attributes applied to functions and methods.
+/// - `Impl` for attributes applied to impl blocks.
+/// - `Module` for attributes applied to modules.
+///
+/// More levels may be added, so matching on an [`AttrLevel`] needs a wildcard arm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum AttrLevel {
     Type,
     Variant,
     Field,
+    Item,
+    Fn,
+    Impl,
+    Module,
+}
+
+impl AttrLevel {
+    /// Every attribute level.
+    pub const ALL: &[Self] = &[
+        Self::Type,
+        Self::Variant,
+        Self::Field,
+        Self::Item,
+        Self::Fn,
+        Self::Impl,
+        Self::Module,
+    ];
 }
 
 /// Trait for attributes with a fixed namespace identifier.
@@ -25,12 +55,133 @@ pub enum AttrLevel {
 pub trait NsAttr: Parse {
     /// The namespace identifier (attribute name).
     ///
+    /// Multi-segment paths are matched segment by segment, so tool-qualified
+    /// or re-exported attribute names such as `"hizli::parse"` are recognized.
+    ///
     /// Example:
     /// ```ignore
     /// const NS: &str = "myattr";
     /// ```
     const NS: &str;
 
+    /// The levels this attribute may appear at, checked by [`NsAttr::parse_at`].
+    ///
+    /// Defaults to [`AttrLevel::ALL`].
+    ///
+    /// Example:
+    /// ```ignore
+    /// const LEVELS: &[AttrLevel] = &[AttrLevel::Type, AttrLevel::Field];
+    /// ```
+    const LEVELS: &[AttrLevel] = AttrLevel::ALL;
+
+    /// The keys accepted inside the attribute, listed in errors such as the one of
+    /// [`NsAttr::from_attrs`] so users can fix an attribute without opening the macro's docs.
+    ///
+    /// Defaults to no keys, which omits the list. The `NsAttr` derive fills it in.
+    ///
+    /// Example:
+    /// ```ignore
+    /// const KEYS: &[&str] = &["skip", "rename"];
+    /// ```
+    const KEYS: &[&str] = &[];
+
+    /// Alternative namespaces accepted in addition to [`NsAttr::NS`].
+    ///
+    /// Eases renames and migrations by accepting a legacy attribute name alongside the new one.
+    /// Defaults to no aliases.
+    ///
+    /// Example:
+    /// ```ignore
+    /// const NS: &str = "hizli";
+    /// const ALIASES: &[&str] = &["bindings"];
+    /// ```
+    const ALIASES: &[&str] = &[];
+
+    /// Returns `true` if `path` names this namespace or one of its aliases,
+    /// see [`NsAttr::NS`] and [`NsAttr::ALIASES`].
+    fn is_ns(path: &Path) -> bool {
+        Self::matched_ns(path).is_some()
+    }
+
+    /// Returns the namespace `path` matched, either [`NsAttr::NS`] or one of [`NsAttr::ALIASES`],
+    /// for example to warn about a legacy name.
+    fn matched_ns(path: &Path) -> Option<&'static str> {
+        std::iter::once(Self::NS)
+            .chain(Self::ALIASES.iter().copied())
+            .find(|ns| path_is(path, ns))
+    }
+
+    /// Parses the arguments of an attribute macro invocation `#[my_attribute_macro(...)]`.
+    ///
+    /// The same type can then configure both helper attributes and attribute macros.
+    /// Empty arguments behave like the bare form, see [`NsAttr::from_flag`].
+    ///
+    /// Example:
+    /// ```ignore
+    /// #[proc_macro_attribute]
+    /// pub fn my_attribute_macro(args: TokenStream, item: TokenStream) -> TokenStream {
+    ///     let args = match MyAttr::from_macro_args(args.into()) {
+    ///         Ok(ok) => ok,
+    ///         Err(err) => return err.into_compile_error().into(),
+    ///     };
+    ///     // ...
+    /// }
+    /// ```
+    fn from_macro_args(args: TokenStream) -> Result<Self> {
+        match args.is_empty() {
+            true => Self::from_flag(Span::call_site()),
+            false => Self::parse.parse2(args),
+        }
+    }
+
+    /// Parses a single attribute already known to be in this namespace.
+    ///
+    /// - `#[myattr(...)]` parses the arguments with [`Parse`].
+    /// - `#[myattr]` defers to [`NsAttr::from_flag`].
+    /// - `#[myattr = value]` defers to [`NsAttr::from_name_value`].
+    fn parse_attr(attr: &Attribute) -> Result<Self> {
+        match &attr.meta {
+            Meta::Path(_) => Self::from_flag(attr.span()),
+            Meta::List(_) => attr.parse_args(),
+            Meta::NameValue(nv) => Self::from_name_value(&nv.value),
+        }
+    }
+
+    /// Builds the attribute from its bare form `#[myattr]`, where `span` points at the attribute.
+    ///
+    /// The default implementation parses an empty argument list, so any [`Parse`]
+    /// implementation accepting empty input (for example one that defaults every key)
+    /// supports marker attributes without a dummy argument.
+    fn from_flag(span: Span) -> Result<Self> {
+        Self::parse
+            .parse2(TokenStream::new())
+            .map_err(|err| Error::new(span, err))
+    }
+
+    /// Builds the attribute from its name-value form `#[myattr = value]`.
+    ///
+    /// The default implementation rejects this form; override it to accept
+    /// attributes such as `#[myattr = "literal"]`.
+    ///
+    /// Example:
+    /// ```ignore
+    /// fn from_name_value(value: &Expr) -> Result<Self> {
+    ///     match value {
+    ///         Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(Self { rename: Some(s.value()) }),
+    ///         other => Err(Error::new_spanned(other, "Expected A String Literal")),
+    ///     }
+    /// }
+    /// ```
+    fn from_name_value(value: &Expr) -> Result<Self> {
+        Err(Error::new(
+            value.span(),
+            format!(
+                "Attribute #[{0} = ...] Is Not Supported, Use #[{0}(...)]",
+                Self::NS
+            ),
+        ))
+    }
+
     /// Attempts to parse the namespaced attribute from a list of attributes.
     ///
     /// Returns:
@@ -46,18 +197,95 @@ pub trait NsAttr: Parse {
     /// }
     /// ```
     fn from_attrs_opt(attrs: &[Attribute]) -> Result<Option<Self>> {
-        let mut res = None;
+        find_attr::<Self>(attrs)?.map(Self::parse_attr).transpose()
+    }
+
+    /// Parses the namespaced attribute like [`NsAttr::from_attrs_opt`] and removes every
+    /// occurrence of it from `attrs`, keeping the order of the remaining attributes.
+    ///
+    /// Intended for attribute macros that re-emit the annotated item, whose helper
+    /// attributes would otherwise be rejected by the compiler. On error `attrs` is left untouched.
+    ///
+    /// Example:
+    /// ```ignore
+    /// for field in item.fields.iter_mut() {
+    ///     let attr = MyAttr::strip(&mut field.attrs)?;
+    ///     // use attr
+    /// }
+    /// quote! { #item }
+    /// ```
+    fn strip(attrs: &mut Vec<Attribute>) -> Result<Option<Self>> {
+        let res = Self::from_attrs_opt(attrs)?;
+        attrs.retain(|attr| !Self::is_ns(attr.path()));
+        Ok(res)
+    }
+
+    /// Returns the attributes outside this namespace and its aliases, in declaration order.
+    ///
+    /// See [`others`](`crate::others`) for the namespace-string form.
+    fn others(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+        attrs.iter().filter(|attr| !Self::is_ns(attr.path()))
+    }
+
+    /// Parses every occurrence of the namespaced attribute, in declaration order.
+    ///
+    /// Unlike [`NsAttr::from_attrs_opt`], repeated attributes are not an error,
+    /// which suits designs like `#[myattr(a)] #[myattr(b)]`.
+    ///
+    /// Example:
+    /// ```ignore
+    /// for attr in MyAttr::from_attrs_all(&input.attrs)? {
+    ///     // use attr
+    /// }
+    /// ```
+    fn from_attrs_all(attrs: &[Attribute]) -> Result<Vec<Self>> {
+        attrs
+            .iter()
+            .filter(|attr| Self::is_ns(attr.path()))
+            .map(Self::parse_attr)
+            .collect()
+    }
+
+    /// Combines two occurrences of this attribute, used by [`NsAttr::from_attrs_merged`].
+    ///
+    /// `other` was declared after `self`, and `span` points at the attribute it came from
+    /// so conflicts can be reported there. The default implementation rejects any repetition.
+    ///
+    /// Example:
+    /// ```ignore
+    /// fn merge(self, other: Self, span: Span) -> Result<Self> {
+    ///     Ok(Self {
+    ///         skip: self.skip || other.skip,
+    ///         rename: match (self.rename, other.rename) {
+    ///             (Some(_), Some(_)) => return Err(Error::new(span, "`rename` Is Already Configured")),
+    ///             (a, b) => a.or(b),
+    ///         },
+    ///     })
+    /// }
+    /// ```
+    fn merge(self, other: Self, span: Span) -> Result<Self> {
+        let _ = other;
+        Err(Error::new(
+            span,
+            format!("Attribute #[{}] Is Already Configured", Self::NS),
+        ))
+    }
+
+    /// Parses every occurrence of the namespaced attribute and combines them with [`NsAttr::merge`],
+    /// so options can be split across multiple `#[myattr(...)]` lines.
+    ///
+    /// Returns `Ok(None)` if no matching attribute was present.
+    fn from_attrs_merged(attrs: &[Attribute]) -> Result<Option<Self>> {
+        let mut res: Option<Self> = None;
         for attr in attrs {
-            if !attr.path().is_ident(Self::NS) {
+            if !Self::is_ns(attr.path()) {
                 continue;
             }
-            if res.is_some() {
-                return Err(Error::new(
-                    attr.span(),
-                    format!("Attribute #[{}] Is Already Configured", Self::NS),
-                ));
-            }
-            res = Some(attr.parse_args()?);
+            let parsed = Self::parse_attr(attr)?;
+            res = Some(match res {
+                Some(prev) => prev.merge(parsed, attr.span())?,
+                None => parsed,
+            });
         }
         Ok(res)
     }
@@ -74,10 +302,20 @@ pub trait NsAttr: Parse {
     fn from_attrs(attrs: &[Attribute], span: Span) -> Result<Self> {
         match Self::from_attrs_opt(attrs)? {
             Some(some) => Ok(some),
-            None => Err(Error::new(
-                span,
-                format!("Attribute #[{}] Is Required", Self::NS),
-            )),
+            None => {
+                let aliases = Self::ALIASES
+                    .iter()
+                    .map(|alias| format!(" Or #[{alias}]"))
+                    .collect::<String>();
+                Err(Error::new(
+                    span,
+                    format!(
+                        "Attribute #[{}]{aliases} Is Required{}",
+                        Self::NS,
+                        accepted_keys(Self::KEYS)
+                    ),
+                ))
+            }
         }
     }
 
@@ -92,7 +330,7 @@ pub trait NsAttr: Parse {
     /// MyAttr::no_attrs(&variant.attrs, AttrLevel::Variant)?;
     /// ```
     fn no_attrs(attrs: &[Attribute], level: AttrLevel) -> Result<()> {
-        match attrs.iter().find(|a| a.path().is_ident(Self::NS)) {
+        match attrs.iter().find(|a| Self::is_ns(a.path())) {
             None => Ok(()),
             Some(attr) => Err(Error::new(
                 attr.span(),
@@ -103,4 +341,149 @@ pub trait NsAttr: Parse {
             )),
         }
     }
+
+    /// Parses the namespaced attribute at a given syntactic level.
+    ///
+    /// If `level` is one of [`NsAttr::LEVELS`] this behaves like [`NsAttr::from_attrs_opt`],
+    /// otherwise it behaves like [`NsAttr::no_attrs`] and returns `Ok(None)` when the attribute is absent.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let container = MyAttr::parse_at(&input.attrs, AttrLevel::Type)?;
+    /// let variant = MyAttr::parse_at(&variant.attrs, AttrLevel::Variant)?;
+    /// ```
+    fn parse_at(attrs: &[Attribute], level: AttrLevel) -> Result<Option<Self>> {
+        match Self::LEVELS.contains(&level) {
+            true => Self::from_attrs_opt(attrs),
+            false => Self::no_attrs(attrs, level).map(|_| None),
+        }
+    }
+
+    /// Builds the [`FieldBinding`] of every field together with its parsed attribute,
+    /// in declaration order, parsing each field's attributes at [`AttrLevel::Field`].
+    ///
+    /// Example:
+    /// ```ignore
+    /// for (field, attr) in MyAttr::for_each_field(&data.fields)? {
+    ///     let skip = attr.is_some_and(|attr| attr.skip);
+    ///     // ...
+    /// }
+    /// ```
+    fn for_each_field(fields: &Fields) -> Result<Vec<(FieldBinding, Option<Self>)>> {
+        FieldBinding::try_from_fields(fields)?
+            .into_iter()
+            .map(|field| {
+                let attr = Self::parse_at(field.attrs(), AttrLevel::Field)?;
+                Ok((field, attr))
+            })
+            .collect()
+    }
+
+    /// Fills the keys left unset on this attribute from `parent`, used by [`NsAttr::resolve_fields`].
+    ///
+    /// `self` is the more specific attribute (for example a field's) and `parent` the
+    /// enclosing one (for example the container's). The default implementation keeps `self` as is.
+    ///
+    /// Example:
+    /// ```ignore
+    /// fn inherit(self, parent: &Self) -> Self {
+    ///     Self {
+    ///         skip: self.skip || parent.skip,
+    ///         rename: self.rename.or_else(|| parent.rename.clone()),
+    ///     }
+    /// }
+    /// ```
+    fn inherit(self, parent: &Self) -> Self {
+        let _ = parent;
+        self
+    }
+
+    /// Parses the arguments of a more specific attribute, filling the keys it leaves unset from
+    /// `parent`, used by [`NsAttr::resolve_fields`] for `#[myattr(...)]` attributes.
+    ///
+    /// The default implementation parses with [`Parse`] and then calls [`NsAttr::inherit`].
+    /// Override it when a key written explicitly must win over `parent` even though its value
+    /// equals the default, as the `NsAttr` derive does for flags written `= false`.
+    fn parse_inherit(input: ParseStream, parent: &Self) -> Result<Self> {
+        Self::parse(input).map(|attr| attr.inherit(parent))
+    }
+
+    /// Resolves the configuration of every field, with field-level attributes overriding
+    /// the container-level `container` through [`NsAttr::parse_inherit`].
+    ///
+    /// Returns one entry per field in declaration order, `None` only when neither
+    /// the field nor the container carries the attribute.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let container = MyAttr::parse_at(&input.attrs, AttrLevel::Type)?;
+    /// for (field, attr) in MyAttr::resolve_fields(container.as_ref(), binding.field_bindings())? {
+    ///     // use field and attr
+    /// }
+    /// ```
+    fn resolve_fields<'a>(
+        container: Option<&Self>,
+        fields: &'a [FieldBinding],
+    ) -> Result<Vec<(&'a FieldBinding, Option<Self>)>>
+    where
+        Self: Clone,
+    {
+        fields
+            .iter()
+            .map(|field| {
+                let found = match Self::LEVELS.contains(&AttrLevel::Field) {
+                    true => find_attr::<Self>(field.attrs())?,
+                    false => Self::no_attrs(field.attrs(), AttrLevel::Field).map(|_| None)?,
+                };
+                let attr = match (found, container) {
+                    (Some(attr), Some(container)) => Some(match &attr.meta {
+                        Meta::List(_) => attr.parse_args_with(|input: ParseStream| {
+                            Self::parse_inherit(input, container)
+                        })?,
+                        _ => Self::parse_attr(attr)?.inherit(container),
+                    }),
+                    (Some(attr), None) => Some(Self::parse_attr(attr)?),
+                    (None, container) => container.cloned(),
+                };
+                Ok((field, attr))
+            })
+            .collect()
+    }
+}
+
+/// Returns the single attribute of `attrs` in the namespace of `T`, rejecting repetitions.
+fn find_attr<T: NsAttr>(attrs: &[Attribute]) -> Result<Option<&Attribute>> {
+    let mut found = attrs.iter().filter(|attr| T::is_ns(attr.path()));
+    match (found.next(), found.next()) {
+        (_, Some(second)) => Err(Error::new(
+            second.span(),
+            format!("Attribute #[{}] Is Already Configured", T::NS),
+        )),
+        (first, None) => Ok(first),
+    }
+}
+
+/// Returns the attributes not in the `::`-separated namespace `ns`, in declaration order.
+///
+/// Useful for macros that consume their own attributes while forwarding others
+/// such as `#[derive]`, `#[doc]` or `#[cfg]`.
+///
+/// Example:
+/// ```ignore
+/// let forwarded = others(&input.attrs, MyAttr::NS);
+/// quote! {
+///     #(#forwarded)*
+///     struct Generated;
+/// }
+/// ```
+pub fn others<'a>(attrs: &'a [Attribute], ns: &str) -> impl Iterator<Item = &'a Attribute> {
+    attrs.iter().filter(move |attr| !path_is(attr.path(), ns))
+}
+
+/// Returns `true` if `path` matches the `::`-separated namespace `ns`.
+pub(crate) fn path_is(path: &Path, ns: &str) -> bool {
+    let mut expected = ns.split("::");
+    path.segments.iter().all(|segment| {
+        segment.arguments.is_none() && expected.next().is_some_and(|ns| segment.ident == ns)
+    }) && expected.next().is_none()
 }
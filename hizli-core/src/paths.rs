@@ -0,0 +1,51 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Generics, Ident};
+
+/// Generates the type path of `ident` applied to its own generic parameters.
+///
+/// For `struct Foo<'a, T: Clone, const N: usize>` this produces:
+///
+/// ```text
+/// Foo<'a, T, N>
+/// ```
+///
+/// Bounds and defaults are stripped, so the result is valid in type position.
+pub fn type_path(ident: &Ident, generics: &Generics) -> TokenStream {
+    let (_, type_gen, _) = generics.split_for_impl();
+
+    quote! { #ident #type_gen }
+}
+
+/// Generates the turbofish path of `ident` applied to its own generic parameters.
+///
+/// For `struct Foo<'a, T: Clone, const N: usize>` this produces:
+///
+/// ```text
+/// Foo::<'a, T, N>
+/// ```
+///
+/// Unlike [`type_path`], the result is valid in expression position, such as `Foo::<T>::new()`.
+/// A type without generic parameters produces the bare identifier.
+pub fn turbofish(ident: &Ident, generics: &Generics) -> TokenStream {
+    let (_, type_gen, _) = generics.split_for_impl();
+    let turbofish = type_gen.as_turbofish();
+
+    quote! { #ident #turbofish }
+}
+
+/// Generates a fully qualified path to an associated item of a trait.
+///
+/// ```text
+/// <Self as ::core::clone::Clone>::clone
+/// <Foo<T> as Trait<U>>::Output
+/// ```
+///
+/// Use this instead of `Type::item` whenever the item could be ambiguous between traits.
+pub fn qualified_path(
+    self_ty: impl ToTokens,
+    trait_path: impl ToTokens,
+    item: &Ident,
+) -> TokenStream {
+    quote! { <#self_ty as #trait_path>::#item }
+}
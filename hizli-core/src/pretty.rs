@@ -0,0 +1,25 @@
+use quote::ToTokens;
+use syn::{File, Result};
+
+/// Formats generated code as Rust source with `prettyplease`, enabled by the `pretty` feature.
+///
+/// Implemented for everything that prints to tokens, so a macro's output can be logged or
+/// written to disk readably:
+///
+/// ```ignore
+/// let tokens = quote! { impl Marker for Foo {} };
+/// println!("{}", tokens.to_pretty_string()?);
+/// ```
+pub trait ToPrettyString {
+    /// Formats the tokens as a Rust file.
+    ///
+    /// Returns the parse error if the tokens are not a sequence of items.
+    fn to_pretty_string(&self) -> Result<String>;
+}
+
+impl<T: ToTokens + ?Sized> ToPrettyString for T {
+    fn to_pretty_string(&self) -> Result<String> {
+        let file = syn::parse2::<File>(self.to_token_stream())?;
+        Ok(prettyplease::unparse(&file))
+    }
+}
@@ -0,0 +1,33 @@
+//! Support code for the derives of `hizli-macros`, not part of the public API.
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+
+use crate::Spanable;
+
+/// The field a derived `Spanable` takes its span from.
+///
+/// `(&SpanOf(field)).__hizli_span()` goes through [`Spanable`] when the field implements it, and
+/// falls back to [`Spanned`] otherwise, so custom keywords, `syn` nodes and user `ToTokens` types
+/// keep working as the first field.
+pub struct SpanOf<'a, T: ?Sized>(pub &'a T);
+
+pub trait ViaSpanable {
+    fn __hizli_span(&self) -> Span;
+}
+
+impl<T: Spanable + ?Sized> ViaSpanable for SpanOf<'_, T> {
+    fn __hizli_span(&self) -> Span {
+        self.0.spanable()
+    }
+}
+
+pub trait ViaSpanned {
+    fn __hizli_span(&self) -> Span;
+}
+
+impl<T: Spanned + ?Sized> ViaSpanned for &SpanOf<'_, T> {
+    fn __hizli_span(&self) -> Span {
+        self.0.span()
+    }
+}
@@ -1,6 +1,14 @@
 /// A convenience macro for wrapping procedural macro entry points with uniform
 /// error handling and output conversion.
 ///
+/// With the `debug` feature, the expansion can be dumped through `HIZLI_EXPAND_DEBUG`,
+/// see [`dump_expansion`](`crate::dump_expansion`).
+///
+/// Errors become `compile_error!` invocations, see [`error_output`](`crate::error_output`). With
+/// the `proc-macro-error` feature, `out!(handler, input, emit)` emits them through
+/// `proc_macro_error2` instead, for entry points annotated with `#[proc_macro_error]`, see
+/// `emit_error_output`.
+///
 /// # Syntax
 ///
 /// ```no_run
@@ -21,12 +29,22 @@
 /// ```
 #[macro_export]
 macro_rules! out {
-    ($handler: path, $input: tt) => {
-        ::proc_macro::TokenStream::from(match $handler(::syn::parse_macro_input!($input)) {
-            ::core::result::Result::Err(err) => ::syn::Error::into_compile_error(err),
-            ::core::result::Result::Ok(ok) => ok,
+    (@output $output: path, $handler: path, $input: tt) => {
+        ::proc_macro::TokenStream::from({
+            let tokens = match $handler(::syn::parse_macro_input!($input)) {
+                ::core::result::Result::Err(err) => $output(err),
+                ::core::result::Result::Ok(ok) => ok,
+            };
+            $crate::dump_expansion(::core::stringify!($handler), &tokens);
+            tokens
         })
     };
+    ($handler: path, $input: tt) => {
+        $crate::out!(@output $crate::error_output, $handler, $input)
+    };
+    ($handler: path, $input: tt, emit) => {
+        $crate::out!(@output $crate::emit_error_output, $handler, $input)
+    };
 }
 
 /// Converts Results Into [`syn::Error`] and bubbles.
@@ -49,3 +67,24 @@ macro_rules! tri {
         }
     };
 }
+
+/// Emits a compiler warning from a macro handler, see [`warning`](`crate::warning`).
+///
+/// Evaluates to a [`proc_macro2::TokenStream`] that must be spliced into the generated output.
+///
+/// # Syntax
+///
+/// ```ignore
+/// let warn = emit_warning!(field.span(), "Field `{}` Is Never Parsed", field_name);
+///
+/// Ok(quote! {
+///     #warn
+///     // output
+/// })
+/// ```
+#[macro_export]
+macro_rules! emit_warning {
+    ($span: expr, $($fmt: tt)+) => {
+        $crate::warning($span, &::std::format!($($fmt)+))
+    };
+}
@@ -0,0 +1,116 @@
+use syn::{Attribute, File, Ident, Item, Result};
+
+use crate::{EnumBinding, NsAttr, SignatureBinding, StructBinding, ns_attr::path_is};
+
+/// The binding produced for a single scanned item.
+#[derive(Clone)]
+pub enum ItemBinding {
+    Struct(StructBinding),
+    Enum(EnumBinding),
+    Fn(Box<SignatureBinding>),
+}
+
+/// A struct, enum or function found while scanning a [`syn::File`].
+///
+/// Carries the item’s binding together with its identifier, attributes,
+/// and the path of inline modules enclosing it.
+#[derive(Clone)]
+pub struct ScannedItem {
+    module_path: Vec<Ident>,
+    ident: Ident,
+    attrs: Vec<Attribute>,
+    binding: ItemBinding,
+}
+
+impl ScannedItem {
+    /// Returns the identifiers of the inline modules enclosing this item, outermost first.
+    ///
+    /// Items at the root of the file return an empty slice.
+    pub fn module_path(&self) -> &[Ident] {
+        &self.module_path
+    }
+
+    /// Returns the identifier of the item.
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Returns the attributes declared on the item.
+    pub fn attrs(&self) -> &[Attribute] {
+        &self.attrs
+    }
+
+    /// Returns the [`ItemBinding`] of the item.
+    pub fn binding(&self) -> &ItemBinding {
+        &self.binding
+    }
+
+    /// Returns `true` if the item carries an attribute in the namespace `ns`,
+    /// which may be a multi-segment path like `"hizli::parse"`.
+    pub fn has_attr(&self, ns: &str) -> bool {
+        self.attrs.iter().any(|attr| path_is(attr.path(), ns))
+    }
+
+    /// Parses the namespaced attribute `A` from the item, see [`NsAttr::from_attrs_opt`].
+    pub fn attr<A: NsAttr>(&self) -> Result<Option<A>> {
+        A::from_attrs_opt(&self.attrs)
+    }
+}
+
+/// Collects the bindings of every struct, enum and function in a [`syn::File`].
+///
+/// Inline modules (`mod name { ... }`) are walked recursively; out-of-line modules
+/// (`mod name;`) are not followed. Items are returned in declaration order.
+///
+/// # Usage
+///
+/// ```ignore
+/// let file = syn::parse_file(&source)?;
+///
+/// for item in scan_file(&file).iter().filter(|item| item.has_attr("myattr")) {
+///     // generate code for `item`
+/// }
+/// ```
+pub fn scan_file(file: &File) -> Vec<ScannedItem> {
+    let mut out = Vec::new();
+    scan_items(&file.items, &mut Vec::new(), &mut out);
+    out
+}
+
+fn scan_items(items: &[Item], module_path: &mut Vec<Ident>, out: &mut Vec<ScannedItem>) {
+    for item in items {
+        let (ident, attrs, binding) = match item {
+            Item::Struct(s) => (
+                &s.ident,
+                &s.attrs,
+                ItemBinding::Struct(StructBinding::from_item_struct(s)),
+            ),
+            Item::Enum(e) => (
+                &e.ident,
+                &e.attrs,
+                ItemBinding::Enum(EnumBinding::from_item_enum(e)),
+            ),
+            Item::Fn(f) => (
+                &f.sig.ident,
+                &f.attrs,
+                ItemBinding::Fn(Box::new(SignatureBinding::from_item_fn(f))),
+            ),
+            Item::Mod(m) => {
+                if let Some((_, content)) = &m.content {
+                    module_path.push(m.ident.clone());
+                    scan_items(content, module_path, out);
+                    module_path.pop();
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        out.push(ScannedItem {
+            module_path: module_path.clone(),
+            ident: ident.clone(),
+            attrs: attrs.clone(),
+            binding,
+        });
+    }
+}
@@ -0,0 +1,112 @@
+use std::ops::{Deref, DerefMut};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    Result, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// Parses the separator after an item of a separated list, accepting a trailing separator.
+///
+/// Returns `false` once the input is exhausted, either because the item was the last one
+/// or because the separator was trailing, so a list parses with the same loop whether or not
+/// its last item is followed by a separator:
+///
+/// ```ignore
+/// while !input.is_empty() {
+///     items.push(input.parse()?);
+///     if !parse_separator::<Token![,]>(input)? {
+///         break;
+///     }
+/// }
+/// ```
+pub fn parse_separator<P: Parse>(input: ParseStream) -> Result<bool> {
+    if input.is_empty() {
+        return Ok(false);
+    }
+    input.parse::<P>()?;
+    Ok(!input.is_empty())
+}
+
+/// A list of `T` separated by `P`, with an optional trailing separator.
+///
+/// Wraps a [`Punctuated`] so the list can be a field of a `#[derive(Parse)]` type:
+/// it parses with [`Punctuated::parse_terminated`], consuming the rest of the input,
+/// and prints the separators exactly as parsed, trailing one included.
+///
+/// Example:
+/// ```ignore
+/// #[derive(Parse)]
+/// struct Args {
+///     name: Ident,
+///     _colon: Token![:],
+///     values: Separated<Expr>,
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Separated<T, P = Token![,]>(pub Punctuated<T, P>);
+
+impl<T, P> Separated<T, P> {
+    /// Returns the wrapped [`Punctuated`].
+    pub fn into_inner(self) -> Punctuated<T, P> {
+        self.0
+    }
+}
+
+impl<T, P> Default for Separated<T, P> {
+    fn default() -> Self {
+        Self(Punctuated::new())
+    }
+}
+
+impl<T, P> Deref for Separated<T, P> {
+    type Target = Punctuated<T, P>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, P> DerefMut for Separated<T, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, P> From<Punctuated<T, P>> for Separated<T, P> {
+    fn from(punctuated: Punctuated<T, P>) -> Self {
+        Self(punctuated)
+    }
+}
+
+impl<T, P> IntoIterator for Separated<T, P> {
+    type Item = T;
+    type IntoIter = syn::punctuated::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, P> IntoIterator for &'a Separated<T, P> {
+    type Item = &'a T;
+    type IntoIter = syn::punctuated::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: Parse, P: Parse> Parse for Separated<T, P> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Punctuated::parse_terminated(input).map(Self)
+    }
+}
+
+impl<T: ToTokens, P: ToTokens> ToTokens for Separated<T, P> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+use proc_macro2::Span;
+use syn::Error;
+
+/// Formats the location of `span` as `file:line:col`, the way rustc prints it after `-->`.
+///
+/// Lines and columns are 1-based. Spans created outside of a compiler, for example by
+/// `syn::parse_str`, report an artificial file such as `<parsed string 1>`.
+///
+/// # Usage
+/// ```ignore
+/// panic!("Unexpected Token At {}", span_location(token.span()));
+/// ```
+pub fn span_location(span: Span) -> String {
+    let start = span.start();
+    format!("{}:{}:{}", span.file(), start.line, start.column + 1)
+}
+
+/// Renders the line of `source` that `span` starts on with carets under the spanned text,
+/// in the style of rustc's diagnostics.
+///
+/// A span covering several lines is underlined to the end of its first line.
+/// Returns `None` when `span` does not start on a line of `source`.
+///
+/// # Usage
+/// ```ignore
+/// let source = std::fs::read_to_string("src/schema.rs")?;
+/// let file = syn::parse_file(&source)?;
+/// eprintln!("{}", span_excerpt(file.items[0].span(), &source).unwrap_or_default());
+/// ```
+pub fn span_excerpt(span: Span, source: &str) -> Option<String> {
+    let (start, end) = (span.start(), span.end());
+    let line = source.lines().nth(start.line.checked_sub(1)?)?;
+    let len = line.chars().count();
+    let to = match end.line == start.line {
+        true => end.column.min(len),
+        false => len,
+    };
+    let carets = to.saturating_sub(start.column).max(1);
+
+    let number = start.line.to_string();
+    let pad = " ".repeat(number.len());
+    Some(format!(
+        "{pad} |\n{number} | {line}\n{pad} | {}{}",
+        " ".repeat(start.column),
+        "^".repeat(carets),
+    ))
+}
+
+/// Renders every message of `err` with its [`span_location`] and [`span_excerpt`],
+/// for build scripts and panic messages that are not rendered by the compiler.
+///
+/// # Usage
+/// ```ignore
+/// let source = std::fs::read_to_string("src/schema.rs")?;
+/// if let Err(err) = generate(&source) {
+///     panic!("{}", render_error(&err, &source));
+/// }
+/// ```
+pub fn render_error(err: &Error, source: &str) -> String {
+    let mut out = String::new();
+    for (idx, err) in err.into_iter().enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        let span = err.span();
+        let _ = write!(out, "error: {err}\n --> {}", span_location(span));
+        if let Some(excerpt) = span_excerpt(span, source) {
+            let _ = write!(out, "\n{excerpt}");
+        }
+    }
+    out
+}
@@ -0,0 +1,198 @@
+use std::{rc::Rc, sync::Arc};
+
+use proc_macro2::Span;
+use syn::{punctuated::Punctuated, spanned::Spanned};
+
+use crate::Separated;
+
+/// Types that can point a diagnostic at themselves, implemented by `#[derive(Spanable)]`.
+///
+/// Besides derived types, it is implemented for the `syn` and `proc_macro2` tokens, literals and
+/// common nodes, and for the containers grammars wrap them in, so derived and manual code can
+/// delegate through a field whatever it holds:
+///
+/// - `&T`, `Box<T>`, `Rc<T>` and `Arc<T>` are the span of `T`.
+/// - `Option<T>` is the span of the value, or [`Span::call_site`] when `None`, see [`span_or`].
+/// - `Vec<T>`, `[T]`, `Punctuated<T, P>` and `Separated<T, P>` join the spans of their first and
+///   last elements, see [`join_spans`], and are [`Span::call_site`] when empty.
+///
+/// Other types implement it by hand, usually through [`Spanned`] for types that are `ToTokens`:
+///
+/// ```ignore
+/// syn::custom_keyword!(select);
+///
+/// impl Spanable for select {
+///     fn spanable(&self) -> Span {
+///         self.span
+///     }
+/// }
+/// ```
+///
+/// # Trait Objects
+///
+/// `Spanable` is dyn-compatible, and the pointer impls accept unsized types, so heterogeneous
+/// nodes can be stored behind `dyn Spanable`, or behind a trait of the interpreter or linter that
+/// has `Spanable` as a supertrait, and still produce diagnostics:
+///
+/// ```ignore
+/// trait Node: Spanable {
+///     fn check(&self) -> Result<()>;
+/// }
+///
+/// let nodes: Vec<Box<dyn Node>> = ...;
+/// let error = Error::new(nodes.spanable(), "Invalid Program");
+/// ```
+pub trait Spanable {
+    /// Returns the span of `self`.
+    fn spanable(&self) -> Span;
+}
+
+// Fails to compile if `Spanable` stops being dyn-compatible.
+const _: Option<&dyn Spanable> = None;
+
+/// Returns a span covering `first` through `last`, or `first` when they cannot be joined, as on
+/// stable outside of `proc_macro2`'s fallback implementation.
+pub fn join_spans(first: Span, last: Span) -> Span {
+    first.join(last).unwrap_or(first)
+}
+
+/// Returns the span of `value`, or `fallback` when it is `None`.
+pub fn span_or<T: Spanable>(value: Option<&T>, fallback: Span) -> Span {
+    value.map_or(fallback, Spanable::spanable)
+}
+
+/// Returns the joined span of the first and last of `items`, or [`Span::call_site`] when empty.
+fn span_of_items<'a, T: Spanable + 'a>(mut items: impl DoubleEndedIterator<Item = &'a T>) -> Span {
+    match (items.next(), items.next_back()) {
+        (Some(first), Some(last)) => join_spans(first.spanable(), last.spanable()),
+        (Some(first), None) => first.spanable(),
+        (None, _) => Span::call_site(),
+    }
+}
+
+impl<T: Spanable + ?Sized> Spanable for &T {
+    fn spanable(&self) -> Span {
+        T::spanable(self)
+    }
+}
+
+impl<T: Spanable + ?Sized> Spanable for Box<T> {
+    fn spanable(&self) -> Span {
+        T::spanable(self)
+    }
+}
+
+impl<T: Spanable + ?Sized> Spanable for Rc<T> {
+    fn spanable(&self) -> Span {
+        T::spanable(self)
+    }
+}
+
+impl<T: Spanable + ?Sized> Spanable for Arc<T> {
+    fn spanable(&self) -> Span {
+        T::spanable(self)
+    }
+}
+
+impl<T: Spanable> Spanable for Option<T> {
+    fn spanable(&self) -> Span {
+        span_or(self.as_ref(), Span::call_site())
+    }
+}
+
+impl<T: Spanable> Spanable for [T] {
+    fn spanable(&self) -> Span {
+        span_of_items(self.iter())
+    }
+}
+
+impl<T: Spanable> Spanable for Vec<T> {
+    fn spanable(&self) -> Span {
+        span_of_items(self.iter())
+    }
+}
+
+impl<T: Spanable, P> Spanable for Punctuated<T, P> {
+    fn spanable(&self) -> Span {
+        span_of_items(self.iter())
+    }
+}
+
+impl<T: Spanable, P> Spanable for Separated<T, P> {
+    fn spanable(&self) -> Span {
+        self.0.spanable()
+    }
+}
+
+impl Spanable for Span {
+    fn spanable(&self) -> Span {
+        *self
+    }
+}
+
+/// Implements [`Spanable`] through [`Spanned`] for types that are `ToTokens`.
+macro_rules! spanned {
+    ($($ty: ty),* $(,)?) => {
+        $(
+            impl Spanable for $ty {
+                fn spanable(&self) -> Span {
+                    Spanned::span(self)
+                }
+            }
+        )*
+    };
+}
+
+spanned! {
+    proc_macro2::Ident, proc_macro2::Punct, proc_macro2::Literal, proc_macro2::Group,
+    proc_macro2::TokenTree, proc_macro2::TokenStream,
+    syn::Lifetime, syn::Lit, syn::LitStr, syn::LitByteStr, syn::LitCStr, syn::LitByte,
+    syn::LitChar, syn::LitInt, syn::LitFloat, syn::LitBool,
+    syn::Expr, syn::Type, syn::Path, syn::Attribute, syn::Meta, syn::Visibility, syn::Member,
+    syn::Macro, syn::Generics,
+}
+
+spanned! {
+    syn::token::Abstract, syn::token::As, syn::token::Async, syn::token::Auto, syn::token::Await,
+    syn::token::Become, syn::token::Box, syn::token::Break, syn::token::Const,
+    syn::token::Continue, syn::token::Crate, syn::token::Default, syn::token::Do,
+    syn::token::Dyn, syn::token::Else, syn::token::Enum, syn::token::Extern, syn::token::Final,
+    syn::token::Fn, syn::token::For, syn::token::If, syn::token::Impl, syn::token::In,
+    syn::token::Let, syn::token::Loop, syn::token::Macro, syn::token::Match, syn::token::Mod,
+    syn::token::Move, syn::token::Mut, syn::token::Override, syn::token::Priv, syn::token::Pub,
+    syn::token::Raw, syn::token::Ref, syn::token::Return, syn::token::SelfType,
+    syn::token::SelfValue, syn::token::Static, syn::token::Struct, syn::token::Super,
+    syn::token::Trait, syn::token::Try, syn::token::Type, syn::token::Typeof,
+    syn::token::Union, syn::token::Unsafe, syn::token::Unsized, syn::token::Use,
+    syn::token::Virtual, syn::token::Where, syn::token::While, syn::token::Yield,
+    syn::token::Underscore,
+}
+
+spanned! {
+    syn::token::And, syn::token::AndAnd, syn::token::AndEq, syn::token::At, syn::token::Caret,
+    syn::token::CaretEq, syn::token::Colon, syn::token::Comma, syn::token::Dollar,
+    syn::token::Dot, syn::token::DotDot, syn::token::DotDotDot, syn::token::DotDotEq,
+    syn::token::Eq, syn::token::EqEq, syn::token::FatArrow, syn::token::Ge, syn::token::Gt,
+    syn::token::LArrow, syn::token::Le, syn::token::Lt, syn::token::Minus, syn::token::MinusEq,
+    syn::token::Ne, syn::token::Not, syn::token::Or, syn::token::OrEq, syn::token::OrOr,
+    syn::token::PathSep, syn::token::Percent, syn::token::PercentEq, syn::token::Plus,
+    syn::token::PlusEq, syn::token::Pound, syn::token::Question, syn::token::RArrow,
+    syn::token::Semi, syn::token::Shl, syn::token::ShlEq, syn::token::Shr, syn::token::ShrEq,
+    syn::token::Slash, syn::token::SlashEq, syn::token::Star, syn::token::StarEq,
+    syn::token::Tilde,
+}
+
+/// Implements [`Spanable`] for the delimiter tokens, joining their open and close spans.
+macro_rules! delimiters {
+    ($($ty: ty),* $(,)?) => {
+        $(
+            impl Spanable for $ty {
+                fn spanable(&self) -> Span {
+                    self.span.join()
+                }
+            }
+        )*
+    };
+}
+
+delimiters! { syn::token::Brace, syn::token::Bracket, syn::token::Paren }
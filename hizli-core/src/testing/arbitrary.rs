@@ -0,0 +1,164 @@
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::{Ident, LitBool, LitInt, LitStr, Token, parse::Parse};
+
+/// Maximum nesting of [`Rng::nested`] before [`Rng::exhausted`] reports `true`.
+const MAX_DEPTH: usize = 4;
+
+/// Identifiers produced for [`Ident`], none of which are keywords.
+const IDENTS: &[&str] = &["a", "b", "foo", "bar", "value", "x1", "_y", "Name"];
+
+/// A small deterministic random number generator driving [`ArbitrarySyntax`].
+///
+/// The same seed always produces the same values, so failing cases are reproducible.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+    depth: usize,
+}
+
+impl Rng {
+    /// Creates a generator from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+            depth: 0,
+        }
+    }
+
+    /// Returns the next pseudo-random `u64` (xorshift64*).
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a value in `0..n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Rng::below Requires A Non-Zero Bound");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns a random `bool`.
+    pub fn coin(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Runs `f` one level deeper, used by recursive [`ArbitrarySyntax`] implementations.
+    pub fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.depth += 1;
+        let res = f(self);
+        self.depth -= 1;
+        res
+    }
+
+    /// Returns `true` once generation is nested deeply enough that recursive
+    /// implementations should pick a non-recursive form.
+    pub fn exhausted(&self) -> bool {
+        self.depth >= MAX_DEPTH
+    }
+}
+
+/// Generates arbitrary values of a syntax type, for checking that its
+/// [`Parse`] and [`ToTokens`] implementations agree with [`roundtrip`].
+///
+/// Implemented for [`Ident`], [`LitStr`], [`LitInt`], [`LitBool`], `Box<T>`, `Option<T>` and the punctuation
+/// and keyword tokens of `Token![...]`. `#[derive(ArbitrarySyntax)]` implements it for structs
+/// field by field and for enums by picking a variant, the first variant once [`Rng::exhausted`].
+pub trait ArbitrarySyntax: Sized {
+    /// Generates a value from `rng`.
+    fn arbitrary(rng: &mut Rng) -> Self;
+}
+
+impl ArbitrarySyntax for Ident {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Ident::new(IDENTS[rng.below(IDENTS.len())], Span::call_site())
+    }
+}
+
+impl ArbitrarySyntax for LitStr {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        let value = match rng.below(3) {
+            0 => String::new(),
+            1 => IDENTS[rng.below(IDENTS.len())].to_string(),
+            _ => "with \"quotes\" and \\ escapes".to_string(),
+        };
+        LitStr::new(&value, Span::call_site())
+    }
+}
+
+impl ArbitrarySyntax for LitInt {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        LitInt::new(&(rng.next_u64() % 1000).to_string(), Span::call_site())
+    }
+}
+
+impl ArbitrarySyntax for LitBool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        LitBool::new(rng.coin(), Span::call_site())
+    }
+}
+
+impl<T: ArbitrarySyntax> ArbitrarySyntax for Box<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Box::new(rng.nested(T::arbitrary))
+    }
+}
+
+impl<T: ArbitrarySyntax> ArbitrarySyntax for Option<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        match rng.coin() {
+            true => Some(rng.nested(T::arbitrary)),
+            false => None,
+        }
+    }
+}
+
+macro_rules! arbitrary_tokens {
+    ($($token: tt)*) => {$(
+        impl ArbitrarySyntax for Token![$token] {
+            fn arbitrary(_: &mut Rng) -> Self {
+                Default::default()
+            }
+        }
+    )*};
+}
+
+arbitrary_tokens! {
+    , ; : :: = == != < > <= >= -> => # . .. + - * / % & | ^ ! ? @ _
+    as async const crate enum fn for if impl in let mod mut pub return self Self struct trait type use where while
+}
+
+/// Asserts that `cases` arbitrary values of `T` survive printing and re-parsing:
+/// `parse2(x.to_token_stream())` must succeed and print the same tokens as `x`.
+///
+/// Values are generated from seeds `0..cases`, and a failure reports the seed and tokens.
+///
+/// # Panics
+/// Panics on the first value that fails to re-parse or re-parses differently.
+///
+/// Example:
+/// ```ignore
+/// #[test]
+/// fn grammar_roundtrips() {
+///     roundtrip::<MyDsl>(256);
+/// }
+/// ```
+pub fn roundtrip<T: ArbitrarySyntax + Parse + ToTokens>(cases: u64) {
+    for seed in 0..cases {
+        let value = T::arbitrary(&mut Rng::new(seed));
+        let tokens = value.to_token_stream();
+        let reparsed = match syn::parse2::<T>(tokens.clone()) {
+            Ok(ok) => ok,
+            Err(err) => panic!("Seed {seed}: Failed To Re-Parse `{tokens}`: {err}"),
+        };
+        let reprinted = reparsed.to_token_stream();
+        if reprinted.to_string() != tokens.to_string() {
+            panic!("Seed {seed}: `{tokens}` Re-Parsed As `{reprinted}`");
+        }
+    }
+}
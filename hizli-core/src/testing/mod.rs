@@ -0,0 +1,15 @@
+//! Testing helpers for macro authors, enabled by the `testing` feature.
+//!
+//! | Module | Description |
+//! |---------|-------------|
+//! | `snapshot` | Provides [`expand`] and [`assert_expands!`](`crate::assert_expands`) for snapshotting expansions. |
+//! | `parse` | Provides [`assert_parses!`](`crate::assert_parses`) and [`assert_parse_err!`](`crate::assert_parse_err`) for testing grammars. |
+//! | `arbitrary` | Provides [`ArbitrarySyntax`] and [`roundtrip`] for checking `Parse` against `ToTokens`. |
+
+mod arbitrary;
+mod parse;
+mod snapshot;
+
+pub use arbitrary::{ArbitrarySyntax, Rng, roundtrip};
+pub use parse::{assert_parse_err, assert_parses, assert_parses_to, parse_tokens};
+pub use snapshot::{assert_snapshot, assert_snapshot_file, expand};
@@ -0,0 +1,120 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Result, parse::Parse};
+
+/// Parses `input` as `T`, requiring every token to be consumed.
+///
+/// The tokens are printed and lexed again first, so with the `span-locations` feature of
+/// `proc_macro2` enabled every span refers to the printed input and has source text.
+pub fn parse_tokens<T: Parse>(input: TokenStream) -> Result<T> {
+    let relexed = match input.to_string().parse::<TokenStream>() {
+        Ok(ok) => ok,
+        Err(err) => panic!("Invalid Test Input: {err}"),
+    };
+    syn::parse2(relexed)
+}
+
+/// Asserts that `input` parses as `T` and returns the parsed value.
+///
+/// # Panics
+/// Panics with the parse error if `input` does not parse.
+pub fn assert_parses<T: Parse>(input: TokenStream) -> T {
+    match parse_tokens(input.clone()) {
+        Ok(ok) => ok,
+        Err(err) => panic!("Failed To Parse `{input}`: {err}"),
+    }
+}
+
+/// Asserts that `input` parses as `T` and prints as `expected`, returning the parsed value.
+///
+/// # Panics
+/// Panics if `input` does not parse or prints differently.
+pub fn assert_parses_to<T: Parse + ToTokens>(input: TokenStream, expected: TokenStream) -> T {
+    let value = assert_parses::<T>(input);
+    let actual = value.to_token_stream().to_string();
+    let expected = expected.to_string();
+    if actual != expected {
+        panic!("Parsed Value Does Not Match\n--- expected\n{expected}\n--- actual\n{actual}");
+    }
+    value
+}
+
+/// Asserts that `input` fails to parse as `T` with `message`, and, given `spanned`,
+/// that the error points at exactly that source text.
+///
+/// Checking the span requires the `span-locations` feature of `proc_macro2`.
+///
+/// # Panics
+/// Panics if `input` parses, or the error has a different message or span.
+pub fn assert_parse_err<T: Parse>(input: TokenStream, message: &str, spanned: Option<&str>) {
+    let err = match parse_tokens::<T>(input.clone()) {
+        Ok(_) => panic!("Expected Error `{message}`, `{input}` Parsed Successfully"),
+        Err(err) => err,
+    };
+    if err.to_string() != message {
+        panic!("Unexpected Error Message\n--- expected\n{message}\n--- actual\n{err}");
+    }
+    if let Some(spanned) = spanned {
+        let source = err.span().source_text();
+        if source.as_deref() != Some(spanned) {
+            panic!(
+                "Error `{message}` Points At The Wrong Tokens\n--- expected\n{spanned}\n--- actual\n{}",
+                source.as_deref().unwrap_or("<no source text>")
+            );
+        }
+    }
+}
+
+/// Parses a token literal as a type, see [`assert_parses`](`crate::testing::assert_parses`).
+///
+/// Without an expected value the parsed value is returned for further assertions,
+/// with one it must also print as the expected tokens, which requires `ToTokens`.
+///
+/// # Syntax
+///
+/// ```ignore
+/// let pair = assert_parses!(Pair, { a, b });
+/// assert_eq!(pair.left, "a");
+///
+/// assert_parses!(syn::Expr, { 1 + 2 }, { 1 + 2 });
+/// ```
+#[macro_export]
+macro_rules! assert_parses {
+    ($ty: ty, { $($input: tt)* } $(,)?) => {
+        $crate::testing::assert_parses::<$ty>($crate::quote::quote! { $($input)* })
+    };
+    ($ty: ty, { $($input: tt)* }, { $($expected: tt)* } $(,)?) => {
+        $crate::testing::assert_parses_to::<$ty>(
+            $crate::quote::quote! { $($input)* },
+            $crate::quote::quote! { $($expected)* },
+        )
+    };
+}
+
+/// Asserts that a token literal fails to parse as a type,
+/// see [`assert_parse_err`](`crate::testing::assert_parse_err`).
+///
+/// # Syntax
+///
+/// ```ignore
+/// assert_parse_err!(Pair, { a b }, "expected `,`");
+///
+/// assert_parse_err!(Pair, { a b }, "expected `,`", at = "b");
+/// ```
+#[macro_export]
+macro_rules! assert_parse_err {
+    ($ty: ty, { $($input: tt)* }, $message: expr $(,)?) => {
+        $crate::testing::assert_parse_err::<$ty>(
+            $crate::quote::quote! { $($input)* },
+            $message,
+            ::core::option::Option::None,
+        )
+    };
+    ($ty: ty, { $($input: tt)* }, $message: expr, at = $spanned: expr $(,)?) => {
+        $crate::testing::assert_parse_err::<$ty>(
+            $crate::quote::quote! { $($input)* },
+            $message,
+            ::core::option::Option::Some($spanned),
+        )
+    };
+}
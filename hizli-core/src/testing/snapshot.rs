@@ -0,0 +1,118 @@
+use std::{env, fs, path::Path};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Result, parse::Parse};
+
+use crate::ToPrettyString;
+
+/// Runs `handler` on `input` and formats its output with `prettyplease`.
+///
+/// An error returned by the handler is formatted as the `compile_error!` it expands to,
+/// so error messages can be snapshotted like any other expansion.
+///
+/// # Panics
+/// Panics if `input` cannot be parsed as `I`, or if the handler's output is not a valid Rust file.
+pub fn expand<I: Parse>(
+    handler: impl FnOnce(I) -> Result<TokenStream>,
+    input: TokenStream,
+) -> String {
+    let input = match syn::parse2(input) {
+        Ok(ok) => ok,
+        Err(err) => panic!("Invalid Handler Input: {err}"),
+    };
+    let output = handler(input).unwrap_or_else(|err| err.into_compile_error());
+    match output.to_pretty_string() {
+        Ok(formatted) => formatted,
+        Err(err) => panic!(
+            "Handler Output Is Not A Valid File: {err}\n{}",
+            output.to_token_stream()
+        ),
+    }
+}
+
+/// Asserts that `actual` matches the inline snapshot `expected`.
+///
+/// Both sides are compared line by line with surrounding whitespace trimmed,
+/// so the snapshot can be indented to match the test.
+///
+/// # Panics
+/// Panics with both expansions if they differ.
+pub fn assert_snapshot(actual: &str, expected: &str) {
+    if normalize(actual) != normalize(expected) {
+        panic!(
+            "Expansion Does Not Match The Snapshot\n--- expected\n{expected}\n--- actual\n{actual}"
+        );
+    }
+}
+
+/// Asserts that `actual` matches the snapshot stored at `path`.
+///
+/// When the `HIZLI_BLESS` environment variable is set to `1`, the snapshot is (re)written
+/// instead of compared, which is also how a new snapshot is created.
+///
+/// # Panics
+/// Panics if the expansions differ, or the snapshot is missing, cannot be read or cannot be
+/// written.
+pub fn assert_snapshot_file(actual: &str, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    if env::var_os("HIZLI_BLESS").is_some_and(|bless| bless == "1") {
+        if let Err(err) = fs::write(path, actual) {
+            panic!("Failed To Write Snapshot `{}`: {err}", path.display());
+        }
+        return;
+    }
+    if !path.exists() {
+        panic!(
+            "Missing Snapshot `{}`, Run With `HIZLI_BLESS=1` To Create It\n--- actual\n{actual}",
+            path.display()
+        );
+    }
+    match fs::read_to_string(path) {
+        Ok(expected) => assert_snapshot(actual, &expected),
+        Err(err) => panic!("Failed To Read Snapshot `{}`: {err}", path.display()),
+    }
+}
+
+fn normalize(source: &str) -> Vec<&str> {
+    source
+        .trim()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Snapshot-tests the expansion of a macro handler, see [`expand`](`crate::testing::expand`).
+///
+/// The handler is any `fn(I) -> syn::Result<TokenStream>` where `I: Parse`, such as the
+/// handlers passed to [`out!`](`crate::out`). The snapshot is either an inline string or a file,
+/// see [`assert_snapshot`](`crate::testing::assert_snapshot`) and
+/// [`assert_snapshot_file`](`crate::testing::assert_snapshot_file`).
+///
+/// # Syntax
+///
+/// ```ignore
+/// assert_expands!(my_derive::handler, { struct A; }, "impl MyTrait for A {}");
+///
+/// assert_expands!(
+///     my_derive::handler,
+///     { struct A { a: u8 } },
+///     file = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/a.rs")
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_expands {
+    ($handler: expr, { $($input: tt)* }, file = $path: expr $(,)?) => {
+        $crate::testing::assert_snapshot_file(
+            &$crate::testing::expand($handler, $crate::quote::quote! { $($input)* }),
+            $path,
+        )
+    };
+    ($handler: expr, { $($input: tt)* }, $expected: expr $(,)?) => {
+        $crate::testing::assert_snapshot(
+            &$crate::testing::expand($handler, $crate::quote::quote! { $($input)* }),
+            $expected,
+        )
+    };
+}
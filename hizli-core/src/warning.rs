@@ -0,0 +1,31 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+
+/// Emits a compiler warning pointing at `span` without failing the expansion.
+///
+/// - With the `nightly` feature enabled and inside a procedural macro, the warning is
+///   emitted directly through `proc_macro::Diagnostic` and an empty stream is returned.
+/// - Otherwise a `#[deprecated]` shim is returned; splicing it into the macro output
+///   makes rustc report `message` as a deprecation warning at `span`.
+///
+/// The returned tokens must always be included in the generated output.
+///
+/// Prefer the [`emit_warning!`](`crate::emit_warning`) macro, which accepts format arguments.
+pub fn warning(span: Span, message: &str) -> TokenStream {
+    #[cfg(feature = "nightly")]
+    if proc_macro::is_available() {
+        proc_macro::Diagnostic::spanned(span.unwrap(), proc_macro::Level::Warning, message).emit();
+        return TokenStream::new();
+    }
+
+    let usage = quote_spanned! {span=> hizli_warning };
+
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const hizli_warning: () = ();
+            #usage
+        };
+    }
+}
@@ -0,0 +1,93 @@
+use hizli_core::{
+    FieldBinding, Fragments, StructBinding, StructEnumOnly, VariantBinding, collect_all,
+    derive_span,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Result};
+
+use crate::attr::HizliAttr;
+
+pub fn handler(input: DeriveInput) -> Result<TokenStream> {
+    let attr = HizliAttr::container(&input.attrs)?;
+    let allow = attr.allow_attr();
+    let span = derive_span();
+    let hizli = attr.hizli();
+    let testing = quote! { #hizli::testing };
+    let fragments = attr.fragments();
+
+    let ident = input.ident;
+
+    let (impl_gen, type_gen, where_cl) = input.generics.split_for_impl();
+    let where_cl = attr.where_clause(where_cl);
+
+    let block = match StructEnumOnly::try_new_at(input.data, "ArbitrarySyntax", span)? {
+        StructEnumOnly::Struct(s) => {
+            let binding = StructBinding::try_new(&s.fields)?;
+            let init = binding.construct(exprs(&binding, &testing, &fragments)?);
+            quote! { Self #init }
+        }
+        StructEnumOnly::Enum(e) => {
+            if e.variants.is_empty() {
+                return Err(Error::new(
+                    span,
+                    "#[derive(ArbitrarySyntax)] Requires At Least One Variant",
+                ));
+            }
+            let count = e.variants.len();
+            let arms = collect_all(e.variants.iter().enumerate().map(|(idx, variant)| {
+                let binding = VariantBinding::try_new(variant)?;
+                let construct = binding.qualified_construct(
+                    &quote! { Self },
+                    exprs(binding.struct_binding(), &testing, &fragments)?,
+                );
+                Ok(quote! { #idx => #construct, })
+            }))?;
+            let root = fragments.root();
+            quote! {
+                let choice = match rng.exhausted() {
+                    true => 0,
+                    false => rng.below(#count),
+                };
+                rng.nested(|rng| match choice {
+                    #(#arms)*
+                    _ => #root::unreachable!(),
+                })
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        #allow
+        impl #impl_gen #testing::ArbitrarySyntax for #ident #type_gen #where_cl {
+            #[allow(unused_variables)]
+            fn arbitrary(rng: &mut #testing::Rng) -> Self {
+                #block
+            }
+        }
+    })
+}
+
+/// Returns the expression generating each field, skipped fields using their default.
+fn exprs(
+    binding: &StructBinding,
+    testing: &TokenStream,
+    fragments: &Fragments,
+) -> Result<Vec<TokenStream>> {
+    binding
+        .field_bindings()
+        .iter()
+        .map(|field| expr(field, testing, fragments))
+        .collect()
+}
+
+fn expr(field: &FieldBinding, testing: &TokenStream, fragments: &Fragments) -> Result<TokenStream> {
+    let attr = HizliAttr::field_attrs(field.attrs())?;
+    let ty = field.ty();
+    Ok(match (&attr.skip, &attr.default) {
+        (Some(_), Some(default)) => default.expr(fragments),
+        (Some(_), None) => fragments.default_expr(),
+        (None, _) => quote! { <#ty as #testing::ArbitrarySyntax>::arbitrary(rng) },
+    })
+}
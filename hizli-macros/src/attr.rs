@@ -0,0 +1,523 @@
+use hizli_core::{AttrLevel, Fragments, KeySet, NsAttr, parse_separator, unknown_key};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    Attribute, Error, Expr, Field, Ident, LitStr, Member, Path, PathSegment, Result, Token, Type,
+    Variant, WhereClause, WherePredicate, bracketed,
+    ext::IdentExt,
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    token,
+};
+
+/// The lints allowed by a bare `#[hizli(allow)]`, which generated impls are known to trip.
+pub const DEFAULT_ALLOW: &[&str] = &[
+    "unused_variables",
+    "unused_qualifications",
+    "clippy::needless_question_mark",
+    "clippy::match_single_binding",
+];
+
+const TYPE: AttrLevel = AttrLevel::Type;
+const VARIANT: AttrLevel = AttrLevel::Variant;
+const FIELD: AttrLevel = AttrLevel::Field;
+
+/// Every key of `#[hizli(...)]`, listed once with the levels it is allowed at.
+const KEYS: &[(&str, &[AttrLevel])] = &[
+    ("crate", &[TYPE]),
+    ("bound", &[TYPE]),
+    ("ns", &[TYPE]),
+    ("alias", &[TYPE]),
+    ("inherit", &[TYPE]),
+    ("lenient", &[TYPE]),
+    ("helpers", &[TYPE]),
+    ("fork", &[TYPE]),
+    ("allow", &[TYPE]),
+    ("unordered", &[TYPE]),
+    ("delimited", &[TYPE, VARIANT, FIELD]),
+    ("cursor", &[TYPE]),
+    ("keywords", &[TYPE]),
+    ("context", &[TYPE]),
+    ("root", &[TYPE]),
+    ("alloc", &[TYPE]),
+    ("spanned", &[TYPE]),
+    ("skip", &[FIELD]),
+    ("default", &[FIELD]),
+    ("required", &[FIELD]),
+    ("nested", &[FIELD]),
+    ("unknown", &[FIELD]),
+    ("deprecated", &[FIELD]),
+    ("warnings", &[FIELD]),
+    ("keys", &[FIELD]),
+    ("passthrough", &[FIELD]),
+    ("positional", &[FIELD]),
+    ("requires", &[FIELD]),
+    ("peek_not", &[VARIANT, FIELD]),
+    ("outer", &[FIELD]),
+    ("with", &[FIELD]),
+    ("separator", &[FIELD]),
+    ("trailing", &[FIELD]),
+    ("keyword", &[FIELD]),
+];
+
+/// The `#[hizli(...)]` attribute shared by every hizli derive.
+///
+/// Each derive reads the keys it understands and ignores the others,
+/// so one attribute can configure several derives on the same type.
+///
+/// - `crate = path` (type level): path to a crate re-exporting `syn`, `proc_macro2` and `quote`.
+/// - `root = path` (type level): generated code reaches the standard library through `path`
+///   instead of `::core` and `::std`, such as `::std` or a facade crate re-exporting it.
+/// - `alloc = path` (type level): generated code reaches `Vec` through `path`, such as `::alloc`
+///   alongside `root = ::core`, instead of through the root.
+/// - `bound = "T: Trait, ..."` (type level): extra where predicates for the generated impl.
+/// - `ns = "name"` (type level): the namespace of a derived `NsAttr`.
+/// - `alias = "name"` / `alias = ["a", "b"]` (type level): alias namespaces of a derived `NsAttr`.
+/// - `inherit` (type level): a derived `NsAttr` implements `NsAttr::inherit`.
+/// - `lenient` (type level): a derived `NsAttr` skips unknown keys instead of rejecting them.
+/// - `helpers` (type level): a derived `Parse` factors each enum variant into a helper function.
+/// - `fork` (type level): a derived `Parse` tries each enum variant on a fork instead of peeking.
+/// - `cursor` (type level): a derived `Parse` chooses enum variants by inspecting the next token
+///   once through `input.cursor()` instead of peeking each variant.
+/// - `keywords` (type level): a derived `Parse` also emits the `KEYWORDS` table and `is_keyword`.
+/// - `context` (type level): a derived `Parse` prefixes field errors with the field and type parsed.
+/// - `spanned` (type level): a derived `Parse`, `ToTokens` or `Spanable` spans the code handling
+///   each field at the field type, so a type missing the trait is reported at the field.
+/// - `unordered` (type level): a derived `Parse` reads the fields of a struct as `key = value` clauses in any order.
+/// - `delimited = brace` / `paren` / `bracket` (type, variant and field level): a derived `Parse`
+///   reads the fields inside the delimiter, and a derived `ToTokens` prints them inside it. On
+///   fields, consecutive fields with the same delimiter share one group.
+/// - `allow` / `allow = lint` / `allow = [a, b]` (type level): lints allowed on every generated impl,
+///   [`DEFAULT_ALLOW`] for the bare key.
+/// - `skip` (field level): the field is ignored by the derive.
+/// - `default` / `default = expr` (field level): the value used when the field is not parsed.
+/// - `required` (field level): the key of a derived `NsAttr` must be present.
+/// - `unknown` (field level): the `Vec<Ident>` collecting the unknown keys of a lenient `NsAttr`.
+/// - `deprecated = "old"` / `deprecated = ["a", "b"]` (field level): deprecated names still accepted for a key.
+/// - `warnings` (field level): the `TokenStream` collecting the warnings of a derived `NsAttr`.
+/// - `passthrough` (field level): the `Vec<Meta>` collecting the unknown keys of a lenient `NsAttr` verbatim.
+/// - `keys` (field level): the `KeySet` of the keys present in a derived `NsAttr`.
+/// - `positional` (field level): the key of a derived `NsAttr` may also be given as a leading positional value.
+/// - `requires = field` (field level): a derived `Parse` only parses the `Option` field when the
+///   earlier `Option` field `field` is `Some`.
+/// - `peek_not = Type` (variant and field level): a derived `Parse` only chooses the variant when
+///   the token after its first field is not `Type`, and rejects a field followed by `Type`.
+/// - `outer` (field level): a derived `Parse` fills the `Vec<Attribute>` field with the outer
+///   attributes and doc comments of the input.
+/// - `with = path` (field level): a derived `ToTokens` prints the field with
+///   `path(&field, &mut tokens)`.
+/// - `separator = Type` (field level): a derived `ToTokens` prints the elements of the collection
+///   separated by `Type`, and a derived `Parse` reads a `Vec` or `Punctuated` of them from the
+///   rest of the input.
+/// - `trailing` (field level): a derived `ToTokens` also prints the separator after the last
+///   element, and a derived `Parse` requires it.
+/// - `nested` (field level): the key of a derived `NsAttr` is a group `key(...)` parsed by the field type.
+/// - `keyword` (field level): the `syn::custom_keyword!` type of the field is listed in the
+///   `KEYWORDS` of a derived `Parse` with `keywords`.
+#[derive(Default)]
+pub struct HizliAttr {
+    pub krate: Option<Path>,
+    pub root: Option<Path>,
+    pub alloc: Option<Path>,
+    pub spanned: Option<Ident>,
+    pub bound: Option<Punctuated<WherePredicate, Token![,]>>,
+    pub ns: Option<LitStr>,
+    pub alias: Vec<LitStr>,
+    pub inherit: Option<Ident>,
+    pub lenient: Option<Ident>,
+    pub helpers: Option<Ident>,
+    pub fork: Option<Ident>,
+    pub cursor: Option<Ident>,
+    pub keywords: Option<Ident>,
+    pub context: Option<Ident>,
+    pub allow: Option<Vec<Path>>,
+    pub unordered: Option<Ident>,
+    pub delimited: Option<Delimiter>,
+    pub skip: Option<Ident>,
+    pub default: Option<DefaultValue>,
+    pub required: Option<Ident>,
+    pub nested: Option<Ident>,
+    pub unknown: Option<Ident>,
+    pub deprecated: Vec<LitStr>,
+    pub warnings: Option<Ident>,
+    pub key_set: Option<Ident>,
+    pub passthrough: Option<Ident>,
+    pub positional: Option<Ident>,
+    pub requires: Option<Member>,
+    pub peek_not: Option<Type>,
+    pub outer: Option<Ident>,
+    pub with: Option<Path>,
+    pub separator: Option<Type>,
+    pub trailing: Option<Ident>,
+    pub keyword: Option<Ident>,
+    keys: KeySet,
+}
+
+/// The value of a `delimited` key.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// `brace`, `{ ... }`.
+    Brace,
+    /// `paren`, `( ... )`.
+    Paren,
+    /// `bracket`, `[ ... ]`.
+    Bracket,
+}
+
+impl Parse for Delimiter {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "brace" => Ok(Self::Brace),
+            "paren" => Ok(Self::Paren),
+            "bracket" => Ok(Self::Bracket),
+            _ => Err(Error::new(
+                ident.span(),
+                "Expected `brace`, `paren` Or `bracket`",
+            )),
+        }
+    }
+}
+
+impl Delimiter {
+    /// Returns the name of the `syn::token` type of the delimiter.
+    pub fn token_name(self) -> &'static str {
+        match self {
+            Self::Brace => "Brace",
+            Self::Paren => "Paren",
+            Self::Bracket => "Bracket",
+        }
+    }
+
+    /// Returns the delimiter as shown in expected-one-of messages.
+    pub fn display(self) -> &'static str {
+        match self {
+            Self::Brace => "{ ... }",
+            Self::Paren => "( ... )",
+            Self::Bracket => "[ ... ]",
+        }
+    }
+
+    /// Returns `true` if `ty` names the `syn::token` type of the delimiter.
+    pub fn is_token(self, ty: &Type) -> bool {
+        match ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|last| last.ident == self.token_name()),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `ty` names `DelimitedSpan`, which receives the spans of any delimiter.
+    ///
+    /// Only the last segment of the path is compared, as for [`Delimiter::is_token`], so any
+    /// type named `DelimitedSpan`, whatever its module, matches.
+    pub fn is_span(ty: &Type) -> bool {
+        match ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|last| last.ident == "DelimitedSpan"),
+            _ => false,
+        }
+    }
+
+    /// Returns the path to the `syn::token` type of the delimiter.
+    pub fn token(self, syn: &TokenStream) -> TokenStream {
+        let name = Ident::new(self.token_name(), Span::call_site());
+        quote! { #syn::token::#name }
+    }
+
+    /// Opens the delimiter, binding its token to `__hizli_delimiter` and shadowing `input`
+    /// with the inner buffer.
+    pub fn open(self, syn: &TokenStream) -> TokenStream {
+        let open = match self {
+            Self::Brace => quote! { #syn::braced },
+            Self::Paren => quote! { #syn::parenthesized },
+            Self::Bracket => quote! { #syn::bracketed },
+        };
+        quote! {
+            let content;
+            let __hizli_delimiter = #open!(content in input);
+            let input = &content;
+        }
+    }
+}
+
+/// The value of a `default` key.
+pub enum DefaultValue {
+    /// `default`, using `Default::default()`.
+    Trait,
+    /// `default = expr`.
+    Expr(Expr),
+}
+
+impl DefaultValue {
+    /// Returns the expression producing the default value.
+    pub fn expr(&self, fragments: &Fragments) -> TokenStream {
+        match self {
+            Self::Trait => fragments.default_expr(),
+            Self::Expr(expr) => quote! { #expr },
+        }
+    }
+}
+
+impl NsAttr for HizliAttr {
+    const NS: &str = "hizli";
+}
+
+impl Parse for HizliAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut res = Self::default();
+
+        while !input.is_empty() {
+            let key = Ident::parse_any(input)?;
+            res.keys.claim(&key)?;
+
+            match key.to_string().as_str() {
+                "crate" => {
+                    input.parse::<Token![=]>()?;
+                    res.krate = Some(input.parse()?);
+                }
+                "spanned" => res.spanned = Some(key),
+                "root" => {
+                    input.parse::<Token![=]>()?;
+                    res.root = Some(input.parse()?);
+                }
+                "alloc" => {
+                    input.parse::<Token![=]>()?;
+                    res.alloc = Some(input.parse()?);
+                }
+                "bound" => {
+                    input.parse::<Token![=]>()?;
+                    let lit: LitStr = input.parse()?;
+                    let parser = Punctuated::<WherePredicate, Token![,]>::parse_terminated;
+                    res.bound = Some(parser.parse_str(&lit.value()).map_err(|err| {
+                        Error::new(lit.span(), format!("Invalid `bound`: {err}"))
+                    })?);
+                }
+                "ns" => {
+                    input.parse::<Token![=]>()?;
+                    res.ns = Some(input.parse()?);
+                }
+                "alias" => {
+                    input.parse::<Token![=]>()?;
+                    res.alias = one_or_list(input)?;
+                }
+                "inherit" => res.inherit = Some(key),
+                "lenient" => res.lenient = Some(key),
+                "helpers" => res.helpers = Some(key),
+                "fork" => res.fork = Some(key),
+                "cursor" => res.cursor = Some(key),
+                "keywords" => res.keywords = Some(key),
+                "context" => res.context = Some(key),
+                "unordered" => res.unordered = Some(key),
+                "delimited" => {
+                    input.parse::<Token![=]>()?;
+                    res.delimited = Some(input.parse()?);
+                }
+                "allow" => {
+                    res.allow = Some(match input.peek(Token![=]) {
+                        true => {
+                            input.parse::<Token![=]>()?;
+                            one_or_list(input)?
+                        }
+                        false => DEFAULT_ALLOW
+                            .iter()
+                            .map(|lint| Path {
+                                leading_colon: None,
+                                segments: lint
+                                    .split("::")
+                                    .map(|segment| {
+                                        PathSegment::from(Ident::new(segment, key.span()))
+                                    })
+                                    .collect(),
+                            })
+                            .collect(),
+                    });
+                }
+                "skip" => res.skip = Some(key),
+                "required" => res.required = Some(key),
+                "nested" => res.nested = Some(key),
+                "unknown" => res.unknown = Some(key),
+                "deprecated" => {
+                    input.parse::<Token![=]>()?;
+                    res.deprecated = one_or_list(input)?;
+                }
+                "warnings" => res.warnings = Some(key),
+                "keys" => res.key_set = Some(key),
+                "passthrough" => res.passthrough = Some(key),
+                "positional" => res.positional = Some(key),
+                "outer" => res.outer = Some(key),
+                "separator" => {
+                    input.parse::<Token![=]>()?;
+                    res.separator = Some(input.parse()?);
+                }
+                "trailing" => res.trailing = Some(key),
+                "keyword" => res.keyword = Some(key),
+                "with" => {
+                    input.parse::<Token![=]>()?;
+                    res.with = Some(input.parse()?);
+                }
+                "requires" => {
+                    input.parse::<Token![=]>()?;
+                    res.requires = Some(input.parse()?);
+                }
+                "peek_not" => {
+                    input.parse::<Token![=]>()?;
+                    res.peek_not = Some(input.parse()?);
+                }
+                "default" => {
+                    res.default = Some(match input.peek(Token![=]) {
+                        true => {
+                            input.parse::<Token![=]>()?;
+                            DefaultValue::Expr(input.parse()?)
+                        }
+                        false => DefaultValue::Trait,
+                    });
+                }
+                _ => {
+                    let accepted = KEYS.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+                    return Err(unknown_key(&key, &accepted));
+                }
+            }
+
+            if !parse_separator::<Token![,]>(input)? {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+/// Parses either a single value or a bracketed list of them.
+fn one_or_list<T: Parse>(input: ParseStream) -> Result<Vec<T>> {
+    match input.peek(token::Bracket) {
+        true => {
+            let content;
+            bracketed!(content in input);
+            Ok(Punctuated::<T, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect())
+        }
+        false => Ok(vec![input.parse()?]),
+    }
+}
+
+impl HizliAttr {
+    /// Parses the container attribute, rejecting field-only keys.
+    pub fn container(attrs: &[Attribute]) -> Result<Self> {
+        let res = Self::from_attrs_opt(attrs)?.unwrap_or_default();
+        res.check_level(TYPE)?;
+        Ok(res)
+    }
+
+    /// Parses a field attribute, rejecting container-only keys.
+    pub fn field(field: &Field) -> Result<Self> {
+        Self::field_attrs(&field.attrs)
+    }
+
+    /// Parses the attributes of a field, rejecting container-only keys.
+    pub fn field_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let res = Self::from_attrs_opt(attrs)?.unwrap_or_default();
+        res.check_level(FIELD)?;
+        Ok(res)
+    }
+
+    /// Parses the attributes of an enum variant, rejecting container-only and field-only keys.
+    pub fn variant(variant: &Variant) -> Result<Self> {
+        let res = Self::from_attrs_opt(&variant.attrs)?.unwrap_or_default();
+        res.check_level(VARIANT)?;
+        Ok(res)
+    }
+
+    /// Returns `true` if the field carries `#[hizli(skip)]`.
+    pub fn is_skipped(field: &Field) -> Result<bool> {
+        Ok(Self::field(field)?.skip.is_some())
+    }
+
+    fn check_level(&self, level: AttrLevel) -> Result<()> {
+        let allowed = |key: &Ident| {
+            KEYS.iter()
+                .any(|(known, levels)| key == known && levels.contains(&level))
+        };
+        match self.keys.claimed().iter().find(|key| !allowed(key)) {
+            None => Ok(()),
+            Some(key) => Err(Error::new(
+                key.span(),
+                format!("Key `{key}` Is Not Allowed At The {level:?} Level"),
+            )),
+        }
+    }
+
+    /// Returns the `#[allow(...)]` attribute of `#[hizli(allow)]`, emitted on every generated impl.
+    pub fn allow_attr(&self) -> Option<TokenStream> {
+        self.allow
+            .as_ref()
+            .map(|lints| quote! { #[allow(#(#lints),*)] })
+    }
+
+    /// Returns the path to `syn` used in generated code.
+    pub fn syn(&self) -> TokenStream {
+        match &self.krate {
+            Some(krate) => quote! { #krate::syn },
+            None => quote! { ::syn },
+        }
+    }
+
+    /// Returns the path to `proc_macro2` used in generated code.
+    pub fn proc_macro2(&self) -> TokenStream {
+        match &self.krate {
+            Some(krate) => quote! { #krate::proc_macro2 },
+            None => quote! { ::proc_macro2 },
+        }
+    }
+
+    /// Returns the path to `quote` used in generated code.
+    pub fn quote(&self) -> TokenStream {
+        match &self.krate {
+            Some(krate) => quote! { #krate::quote },
+            None => quote! { ::quote },
+        }
+    }
+
+    /// Returns the [`Fragments`] of the expansion, rooted at `#[hizli(root = ...)]` and reaching
+    /// `Vec` through `#[hizli(alloc = ...)]` when set.
+    pub fn fragments(&self) -> Fragments {
+        let fragments = match &self.root {
+            Some(root) => Fragments::with_root(quote! { #root }),
+            None => Fragments::new(),
+        };
+        match &self.alloc {
+            Some(alloc) => fragments.with_alloc(quote! { #alloc }),
+            None => fragments,
+        }
+    }
+
+    /// Returns the path to `hizli` used in generated code.
+    pub fn hizli(&self) -> TokenStream {
+        match &self.krate {
+            Some(krate) => quote! { #krate },
+            None => quote! { ::hizli },
+        }
+    }
+
+    /// Returns the where clause of the generated impl, extended with `bound`.
+    pub fn where_clause(&self, where_cl: Option<&WhereClause>) -> TokenStream {
+        let bound = match &self.bound {
+            Some(bound) if !bound.is_empty() => bound,
+            _ => return quote! { #where_cl },
+        };
+        match where_cl {
+            Some(where_cl) => {
+                let predicates = where_cl.predicates.iter();
+                quote! { where #(#predicates,)* #bound }
+            }
+            None => quote! { where #bound },
+        }
+    }
+}
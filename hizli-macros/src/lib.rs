@@ -33,7 +33,7 @@
 //!
 //! ### `#[derive(Spanable)]`
 //!
-//! Implements the `span(&self)` method.
+//! Implements the `hizli::Spanable` trait, whose `spanable(&self)` method returns a span.
 //!
 //! - For **structs**, it returns the span of the first field if one exists,
 //!   or the call-site span if the struct has no fields.  
@@ -55,25 +55,286 @@
 //! let leaf = Node::Other;
 //! let span = leaf.span();
 //! ```
+//!
+//! ---
+//!
+//! ### `#[derive(ToTokens)]`
+//!
+//! Implements [`quote::ToTokens`](https://docs.rs/quote/latest/quote/trait.ToTokens.html),
+//! printing a type the way `#[derive(Parse)]` reads it.
+//!
+//! - **Structs** print each field in declaration order.
+//! - **Enums** print the fields of the variant held.
+//!
+//! #### Example
+//! ```rust
+//! use hizli_macros::{Parse, ToTokens};
+//! use syn::{Token, Ident};
+//!
+//! #[derive(Parse, ToTokens)]
+//! struct Pair {
+//!     left: Ident,
+//!     comma: Token![,],
+//!     right: Ident,
+//! }
+//! ```
+//!
+//! ---
+//!
+//! ### `#[hizli(...)]`
+//!
+//! Every derive understands the same attribute namespace:
+//!
+//! - `#[hizli(crate = path)]` on the type: generated code reaches `syn`, `proc_macro2` and `quote`
+//!   through `path::syn`, `path::proc_macro2` and `path::quote`, e.g. `#[hizli(crate = ::hizli)]`.
+//! - `#[hizli(root = path)]` on the type: generated code reaches the standard library through
+//!   `path` instead of `::core` and `::std`, e.g. `#[hizli(root = ::std)]` or a facade crate.
+//! - `#[hizli(alloc = path)]` on the type: generated code reaches `Vec` through `path` instead of
+//!   the root, e.g. `#[hizli(root = ::core, alloc = ::alloc)]` with `extern crate alloc`.
+//! - `#[hizli(spanned)]` on the type: the derived `Parse`, `ToTokens` and `Spanable` emit the code
+//!   reading, printing or spanning each field with the span of the field type, so a field type that
+//!   does not implement the trait is reported at the field instead of at the derive.
+//! - `#[hizli(bound = "T: Trait")]` on the type: extra where predicates for the generated impl.
+//! - `#[hizli(allow)]` or `#[hizli(allow = [lint, ...])]` on the type: an `#[allow(...)]` on every
+//!   generated impl, by default silencing the lints generated code is known to trip.
+//! - `#[hizli(helpers)]` on an enum: `Parse` constructs each variant in a helper function.
+//! - `#[hizli(fork)]` on an enum: `Parse` tries each variant on a fork of the input.
+//! - `#[hizli(cursor)]` on an enum: `Parse` chooses the variant from one look at the next token.
+//! - `#[hizli(context)]` on the type: `Parse` errors name the field and type that failed.
+//! - `#[hizli(keywords)]` on the type: `Parse` also emits `KEYWORDS` and `is_keyword`.
+//! - `#[hizli(keyword)]` on a `custom_keyword!` field: its keyword is listed in `KEYWORDS`.
+//! - `#[hizli(delimited = brace)]` on a struct or variant: `Parse` reads its fields inside
+//!   `{ ... }`, or `( ... )` and `[ ... ]` for `paren` and `bracket`. On consecutive fields,
+//!   `Parse` reads and `ToTokens` prints just those fields inside the delimiter.
+//! - `#[hizli(peek_not = Token![::])]` on a variant or field: `Parse` rejects the variant or field
+//!   when it is followed by `::`.
+//! - `#[hizli(unordered)]` on a struct: `Parse` reads its fields as `key = value` clauses in any order.
+//! - `#[hizli(skip)]` on a field: `Parse` initializes it with `Default::default()`,
+//!   `Spanable` ignores it when looking for the first field's span, `ToTokens` does not print it.
+//! - `#[hizli(with = path)]` on a field: `ToTokens` prints it with `path(&field, &mut tokens)`.
+//! - `#[hizli(separator = Token![,])]` on a collection field: `ToTokens` prints a separator
+//!   between its elements, and after the last one too with `#[hizli(trailing)]`. `Parse` reads a
+//!   `Vec` or `Punctuated` of separated elements from the rest of the input, where the separator
+//!   after the last element is optional, or required with `#[hizli(trailing)]`.
+//! - `#[hizli(outer)]` on a `Vec<Attribute>` field: `Parse` collects the outer attributes and
+//!   doc comments at that point of the input.
+//! - `#[hizli(requires = field)]` on an `Option` field: `Parse` only parses it when the earlier
+//!   `Option` field `field` is `Some`.
 
 use hizli_core::out;
 use proc_macro::TokenStream;
 
+#[cfg(feature = "testing")]
+mod arbitrary;
+mod attr;
+mod ns_attr;
 mod parse;
 mod spanable;
+mod to_tokens;
+
+/// [`out!`], emitting errors through `proc_macro_error2` with the `proc-macro-error` feature, under
+/// which every entry point is annotated with `#[proc_macro_error]`.
+macro_rules! derive_out {
+    ($handler: path, $input: tt) => {{
+        #[cfg(feature = "proc-macro-error")]
+        let tokens = out!($handler, $input, emit);
+        #[cfg(not(feature = "proc-macro-error"))]
+        let tokens = out!($handler, $input);
+        tokens
+    }};
+}
 
 /// Derive macro that automatically implements [`syn::parse::Parse`] for structs and enums.
 ///
 /// This derive generates a `Parse` implementation suitable for use with the
 /// [`syn::parse`](https://docs.rs/syn/latest/syn/parse/index.html) framework.
 /// It supports both *product types* (structs) and *sum types* (enums).
-#[proc_macro_derive(Parse)]
+///
+/// A `Separated<T, P>` field parses the rest of the input as `T`s separated by `P`, with an
+/// optional trailing separator, and prints back the separators exactly as parsed.
+///
+/// `#[hizli(helpers)]` on an enum moves the construction of each variant into an `#[inline]`
+/// helper function inside an anonymous const, keeping `parse` itself small for enums with
+/// many variants.
+///
+/// `#[hizli(fork)]` on an enum parses each variant in full on a fork of the input instead of
+/// peeking at its first field, taking the first variant that succeeds. When every variant fails,
+/// the error combines the failure of each variant with the expected-one-of message, so users
+/// see why the closest variant did not match.
+///
+/// `#[hizli(cursor)]` on an enum reads the next token from `input.cursor()` once and chooses
+/// among keyword (`Token![fn]`), single character punctuation (`Token![+]`) and delimited variants
+/// with a `match`, instead of peeking every variant in turn. Other variants still peek their
+/// first field, and variants are tried in declaration order either way. Without
+/// `#[hizli(crate = ...)]`, the generated code names `::proc_macro2`.
+///
+/// `#[hizli(context)]` on the type prefixes the errors of each field with
+/// ``While Parsing Field `x` Of `Type` `` (or `` `Enum::Variant` ``), through `hizli::error_context`,
+/// so errors of deep grammars identify every production on the way to the failure. The generated
+/// code names `::hizli`, or the path given by `#[hizli(crate = ...)]`.
+///
+/// `#[hizli(keywords)]` on the type adds an associated `KEYWORDS: &[&str]` table of the keywords
+/// its fields parse, in declaration order, and `fn is_keyword(ident: &Ident) -> bool`, so later
+/// passes can reject identifiers shadowing a keyword. Keywords are `Token![kw]` fields and fields
+/// marked `#[hizli(keyword)]`, whose `syn::custom_keyword!` type is named after its keyword, as in
+/// `kw::select`. Other paths, such as `core::ffi::c_int`, are never keywords.
+///
+/// `#[hizli(delimited = brace)]`, `paren` or `bracket` on a struct or enum variant opens the
+/// delimiter and parses every field from its contents, which must be consumed entirely.
+/// A field of the delimiter's token type, such as `syn::token::Brace`, receives the delimiter
+/// and its span, and a `hizli::DelimitedSpan` field receives its open and close spans. Both are
+/// recognized by the last segment of their path, so any type named `Brace` or `DelimitedSpan`
+/// is treated as such. A delimited variant is chosen by peeking its delimiter.
+///
+/// On fields, `#[hizli(delimited = brace)]` groups consecutive fields with the same delimiter and
+/// parses only them from its contents, so a production such as `fn name { body }` keeps its
+/// surrounding fields outside of the braces:
+///
+/// ```ignore
+/// #[derive(Parse, ToTokens)]
+/// struct Func {
+///     fn_token: Token![fn],
+///     name: Ident,
+///     #[hizli(delimited = brace)]
+///     brace: token::Brace,
+///     #[hizli(delimited = brace)]
+///     body: Ident,
+/// }
+/// ```
+///
+/// `#[hizli(peek_not = Token![::])]` on an enum variant only chooses it when the token after its
+/// first field is not `::`, as in "an `Ident` not followed by `::`", leaving paths to a later
+/// variant. The first field is parsed on a fork to find that token, so it may span several
+/// tokens, such as `Token![::]`. On a field, parsing fails when the field is followed by `::`. `peek_not` variants
+/// cannot be combined with `#[hizli(fork)]`.
+///
+/// `#[hizli(outer)]` on a `Vec<syn::Attribute>` field parses it with `Attribute::parse_outer`,
+/// capturing the `#[...]` attributes and `///` doc comments preceding an item of the DSL. It is
+/// typically the first field of a struct, and cannot be the first field of a peeked variant.
+///
+/// `#[hizli(requires = eq)]` on an `Option<T>` field parses it only when the earlier `Option`
+/// field `eq` is `Some`, and then as a plain `T`, so `name: Type = default` makes the default
+/// mandatory after `=`. Tuple fields name the field they require by index, as in
+/// `#[hizli(requires = 2)]`.
+///
+/// `#[hizli(unordered)]` on a struct with named fields parses comma-separated clauses keyed by
+/// field name, in any order, the shape of most attribute-like mini-languages. `bool` fields are
+/// bare keywords that also accept `= true` or `= false`, and every other key requires `= value`.
+/// `Option<T>` fields are `None` when absent and other fields fall back to their
+/// `#[hizli(default)]` or `Default::default()`. Repeated and unknown keys are rejected.
+///
+/// Accepts the shared `#[hizli(...)]` attribute, see the crate documentation.
+#[proc_macro_derive(Parse, attributes(hizli))]
+#[cfg_attr(feature = "proc-macro-error", proc_macro_error2::proc_macro_error)]
 pub fn parse(input: TokenStream) -> TokenStream {
-    out!(parse::handler::handler, input)
+    derive_out!(parse::handler::handler, input)
 }
 
-/// Derive macro that implements the `span(&self)` method for structs and enums.
-#[proc_macro_derive(Spanable)]
+/// Derive macro that implements `hizli::Spanable` for structs and enums.
+///
+/// The span of the first field that is not `#[hizli(skip)]` is taken through `Spanable`, so the
+/// field may be a token, a literal, another derived type, or any of them in a `Box`, `Option`,
+/// `Vec` or `Punctuated`. A field type that is not `Spanable` falls back to
+/// `syn::spanned::Spanned`, covering custom keywords, `syn` nodes and other `ToTokens` types. The
+/// generated code names `::hizli`, or the path given by `#[hizli(crate = ...)]`.
+///
+/// Accepts the shared `#[hizli(...)]` attribute, see the crate documentation.
+#[proc_macro_derive(Spanable, attributes(hizli))]
+#[cfg_attr(feature = "proc-macro-error", proc_macro_error2::proc_macro_error)]
 pub fn spanable(input: TokenStream) -> TokenStream {
-    out!(spanable::handler::handler, input)
+    derive_out!(spanable::handler::handler, input)
+}
+
+/// Derive macro that implements [`quote::ToTokens`] for structs and enums.
+///
+/// Fields are printed in declaration order with their own `ToTokens`, except `#[hizli(skip)]`
+/// fields, which are not printed, and `#[hizli(outer)]` attributes, which are printed one after
+/// the other. A `#[hizli(delimited = ...)]` struct or variant prints its fields inside the
+/// delimiter, with the span of its delimiter token field when it has one, and so do consecutive
+/// fields sharing a field-level `#[hizli(delimited = ...)]`.
+///
+/// `#[hizli(with = path)]` on a field prints it with `path(&field, &mut tokens)` instead, for
+/// fields stored differently from their surface syntax:
+///
+/// ```ignore
+/// fn lit_str(value: &str, tokens: &mut TokenStream) {
+///     LitStr::new(value, Span::call_site()).to_tokens(tokens);
+/// }
+///
+/// #[derive(ToTokens)]
+/// struct Name {
+///     #[hizli(with = lit_str)]
+///     value: String,
+/// }
+/// ```
+///
+/// Accepts the shared `#[hizli(...)]` attribute, see the crate documentation.
+#[proc_macro_derive(ToTokens, attributes(hizli))]
+#[cfg_attr(feature = "proc-macro-error", proc_macro_error2::proc_macro_error)]
+pub fn to_tokens(input: TokenStream) -> TokenStream {
+    derive_out!(to_tokens::handler::handler, input)
+}
+
+/// Derive macro that implements `NsAttr` and [`syn::parse::Parse`] for a struct of attribute keys.
+///
+/// Every named field is one key of `#[ns(...)]`, where the namespace is set with
+/// `#[hizli(ns = "...")]` on the struct:
+///
+/// - `bool` fields are flags, set by the bare key (`#[ns(skip)]`) or explicitly with
+///   `= true` or `= false` (`#[ns(skip = false)]`).
+/// - `Option<T>` fields are parsed from `key = T` and are `None` when absent.
+/// - Any other `T` is parsed from `key = T` and uses `Default::default()` when absent.
+///
+/// `#[hizli(positional)]` on fields lets their values lead the attribute without a key, in field
+/// order, as in `#[ns("name", skip)]`. Positional arguments must precede keyword arguments, and
+/// a positional field may still be given by key.
+///
+/// `#[hizli(alias = "legacy")]` or `#[hizli(alias = ["a", "b"])]` on the struct also accepts
+/// attributes under the alias namespaces, see `NsAttr::ALIASES`.
+///
+/// `#[hizli(default = expr)]` on a field overrides the value used when its key is absent,
+/// and `#[hizli(skip)]` excludes a field from parsing entirely.
+///
+/// `#[hizli(required)]` makes a key mandatory. All missing required keys are
+/// reported together in a single error.
+///
+/// `#[hizli(nested)]` parses a key as a group `key(...)` with the field type's [`syn::parse::Parse`],
+/// typically another `NsAttr` derive, so options can be configured hierarchically as
+/// `#[ns(sub(x = 1, y))]`. A bare `key` parses the group as if it were empty.
+///
+/// `#[hizli(inherit)]` on the struct implements `NsAttr::inherit` and `NsAttr::parse_inherit`
+/// so a field-level attribute falls back to the container-level one for every unset `bool` and
+/// `Option<T>` key, see `NsAttr::resolve_fields`. A flag written on the field, as in
+/// `skip = false`, wins over the container. Other keys always keep the field-level value.
+///
+/// `#[hizli(lenient)]` on the struct skips unknown keys instead of rejecting them, so older
+/// versions of a macro accept keys introduced by newer ones. A `Vec<Ident>` field marked
+/// `#[hizli(unknown)]` collects the skipped keys, and implies `lenient`. Likewise a `Vec<syn::Meta>`
+/// field marked `#[hizli(passthrough)]` collects them verbatim, to forward to another macro.
+///
+/// `#[hizli(deprecated = "old")]` or `#[hizli(deprecated = ["a", "b"])]` on a field keeps accepting
+/// old names for its key, each use producing a deprecation warning. The warnings are collected in
+/// the `proc_macro2::TokenStream` field marked `#[hizli(warnings)]`, which the macro must include
+/// in its output.
+///
+/// A `KeySet` field marked `#[hizli(keys)]` receives the keys present in the attribute,
+/// for checks such as `check_level_rules`.
+#[proc_macro_derive(NsAttr, attributes(hizli))]
+#[cfg_attr(feature = "proc-macro-error", proc_macro_error2::proc_macro_error)]
+pub fn ns_attr(input: TokenStream) -> TokenStream {
+    derive_out!(ns_attr::handler::handler, input)
+}
+
+/// Derive macro that implements `ArbitrarySyntax` for structs and enums, enabled by the `testing` feature.
+///
+/// Structs generate each field in turn. Enums pick a random variant, falling back to the first
+/// variant once generation is nested deeply, so the first variant should not be recursive.
+/// Fields marked `#[hizli(skip)]` use their `#[hizli(default)]` or `Default::default()`.
+///
+/// Accepts the shared `#[hizli(...)]` attribute, see the crate documentation.
+#[cfg(feature = "testing")]
+#[proc_macro_derive(ArbitrarySyntax, attributes(hizli))]
+#[cfg_attr(feature = "proc-macro-error", proc_macro_error2::proc_macro_error)]
+pub fn arbitrary_syntax(input: TokenStream) -> TokenStream {
+    derive_out!(arbitrary::handler::handler, input)
 }
@@ -0,0 +1,132 @@
+use hizli_core::Fragments;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Field, GenericArgument, Ident, PathArguments, Result, Type, ext::IdentExt};
+
+use crate::attr::{DefaultValue, HizliAttr};
+
+/// How a field of a derived `NsAttr` is filled from the attribute arguments.
+pub enum Shape<'a> {
+    /// `bool`: set by the bare key.
+    Flag,
+    /// `Option<T>`: `key = T`, `None` when absent.
+    Optional(&'a Type),
+    /// `T`: `key = T`.
+    Value(&'a Type),
+}
+
+/// A named field of a derived `NsAttr`, with its key and configuration.
+pub struct KeyField<'a> {
+    pub ident: &'a Ident,
+    pub key: String,
+    pub var: Ident,
+    pub shape: Shape<'a>,
+    pub attr: HizliAttr,
+}
+
+impl<'a> KeyField<'a> {
+    pub fn new(field: &'a Field, ident: &'a Ident) -> Result<Self> {
+        let key = ident.unraw().to_string();
+        let attr = HizliAttr::field(field)?;
+        if let Some(required) = &attr.required {
+            let conflict = match (&attr.skip, &attr.default) {
+                (Some(_), _) => Some("skip"),
+                (None, Some(_)) => Some("default"),
+                (None, None) => None,
+            };
+            if let Some(conflict) = conflict {
+                return Err(Error::new(
+                    required.span(),
+                    format!("Key `required` Conflicts With `{conflict}`"),
+                ));
+            }
+        }
+        if let Some(collector) = attr
+            .unknown
+            .as_ref()
+            .or(attr.warnings.as_ref())
+            .or(attr.key_set.as_ref())
+            .or(attr.passthrough.as_ref())
+        {
+            let conflict = [
+                ("skip", attr.skip.is_some()),
+                ("default", attr.default.is_some()),
+                ("required", attr.required.is_some()),
+                ("nested", attr.nested.is_some()),
+                ("deprecated", !attr.deprecated.is_empty()),
+                ("unknown", attr.unknown.is_some()),
+                ("warnings", attr.warnings.is_some()),
+                ("keys", attr.key_set.is_some()),
+                ("passthrough", attr.passthrough.is_some()),
+                ("positional", attr.positional.is_some()),
+            ]
+            .into_iter()
+            .find(|(key, present)| *present && collector != key);
+            if let Some((conflict, _)) = conflict {
+                return Err(Error::new(
+                    collector.span(),
+                    format!("Key `{collector}` Conflicts With `{conflict}`"),
+                ));
+            }
+        }
+        let shape = shape(&field.ty);
+        for key in [&attr.nested, &attr.positional].into_iter().flatten() {
+            if let Shape::Flag = shape {
+                return Err(Error::new(
+                    key.span(),
+                    format!("Key `{key}` Is Not Allowed On A `bool` Flag"),
+                ));
+            }
+        }
+        if let Some(positional) = &attr.positional {
+            let conflict = [("skip", &attr.skip), ("nested", &attr.nested)]
+                .into_iter()
+                .find(|(_, key)| key.is_some());
+            if let Some((conflict, _)) = conflict {
+                return Err(Error::new(
+                    positional.span(),
+                    format!("Key `positional` Conflicts With `{conflict}`"),
+                ));
+            }
+        }
+        Ok(Self {
+            ident,
+            var: format_ident!("__hizli_{}", key),
+            key,
+            shape,
+            attr,
+        })
+    }
+
+    /// Returns the expression used when the key is absent.
+    pub fn default_expr(&self, fragments: &Fragments) -> TokenStream {
+        match (&self.attr.default, &self.shape) {
+            (Some(default), _) => default.expr(fragments),
+            (None, Shape::Flag) => quote! { false },
+            (None, Shape::Optional(_)) => fragments.none(),
+            (None, Shape::Value(_)) => DefaultValue::Trait.expr(fragments),
+        }
+    }
+}
+
+/// Classifies a field type as a flag, an optional value or a defaulted value.
+pub fn shape(ty: &Type) -> Shape<'_> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return Shape::Value(ty),
+    };
+    if path.is_ident("bool") {
+        return Shape::Flag;
+    }
+    let last = match path.segments.last() {
+        Some(last) if last.ident == "Option" => last,
+        _ => return Shape::Value(ty),
+    };
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Shape::Optional(inner),
+            _ => Shape::Value(ty),
+        },
+        _ => Shape::Value(ty),
+    }
+}
@@ -0,0 +1,386 @@
+use hizli_core::{StructOnly, collect_all, derive_span};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Fields, Ident, Result};
+
+use crate::{
+    attr::HizliAttr,
+    ns_attr::field::{KeyField, Shape},
+};
+
+pub fn handler(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let attr = HizliAttr::container(&attrs)?;
+    let allow = attr.allow_attr();
+    let hizli = attr.hizli();
+    let syn = quote! { #hizli::syn };
+    let fragments = attr.fragments();
+    let (ok, err, some, none) = (
+        fragments.ok(),
+        fragments.err(),
+        fragments.some(),
+        fragments.none(),
+    );
+    let (clone, vec) = (fragments.clone_path(), fragments.vec());
+
+    let ns = match &attr.ns {
+        Some(ns) => ns,
+        None => {
+            return Err(Error::new(
+                ident.span(),
+                "#[derive(NsAttr)] Requires #[hizli(ns = \"...\")]",
+            ));
+        }
+    };
+
+    let (impl_gen, type_gen, where_cl) = generics.split_for_impl();
+    let where_cl = attr.where_clause(where_cl);
+
+    let StructOnly(data) = StructOnly::try_new_at(data, "NsAttr", derive_span())?;
+    let named = match &data.fields {
+        Fields::Named(named) => named,
+        _ => {
+            return Err(Error::new(
+                data.struct_token.span,
+                "#[derive(NsAttr)] Requires Named Fields",
+            ));
+        }
+    };
+
+    let fields = collect_all(
+        named
+            .named
+            .iter()
+            .map(|field| KeyField::new(field, field.ident.as_ref().unwrap())),
+    )?;
+    let parsed = fields
+        .iter()
+        .filter(|f| {
+            f.attr.skip.is_none()
+                && f.attr.unknown.is_none()
+                && f.attr.warnings.is_none()
+                && f.attr.passthrough.is_none()
+                && f.attr.key_set.is_none()
+        })
+        .collect::<Vec<_>>();
+
+    let keys = parsed.iter().map(|f| &f.key).collect::<Vec<_>>();
+
+    let unknown = collector(&fields, |attr| attr.unknown.as_ref())?;
+    let warnings = collector(&fields, |attr| attr.warnings.as_ref())?;
+    let passthrough = collector(&fields, |attr| attr.passthrough.as_ref())?;
+    let renames = parsed
+        .iter()
+        .flat_map(|f| f.attr.deprecated.iter().map(move |old| (old, &f.key)))
+        .collect::<Vec<_>>();
+    if let (Some((old, _)), false) = (renames.first(), warnings) {
+        return Err(Error::new(
+            old.span(),
+            "Deprecated Keys Require A `#[hizli(warnings)]` Field",
+        ));
+    }
+    let warnings = warnings.then(|| {
+        quote! { let mut warnings = #hizli::proc_macro2::TokenStream::new(); }
+    });
+    let renames_old = renames
+        .iter()
+        .map(|(old, _)| old.value())
+        .collect::<Vec<_>>();
+    let renames = renames.iter().map(|(old, key)| {
+        quote! {
+            #old => {
+                warnings.extend(#hizli::deprecated_key(&key, #key));
+                #syn::Ident::new(#key, key.span())
+            }
+        }
+    });
+
+    let lenient = attr.lenient.is_some() || unknown || passthrough;
+    let collect = lenient.then(|| {
+        quote! { let mut unknown = #vec::<#syn::Ident>::new(); }
+    });
+    let collect_passthrough = passthrough.then(|| {
+        quote! { let mut passthrough = #vec::<#syn::Meta>::new(); }
+    });
+    let fallback = match (lenient, passthrough) {
+        (true, true) => quote! {
+            _ => {
+                passthrough.push(#hizli::raw_meta(key.clone(), input)?);
+                unknown.push(key);
+            }
+        },
+        (true, false) => quote! {
+            _ => {
+                #hizli::skip_value(input)?;
+                unknown.push(key);
+            }
+        },
+        (false, _) => quote! {
+            _ => return #err(#hizli::unknown_key(&key, &[#(#keys),*])),
+        },
+    };
+
+    let positional = parsed
+        .iter()
+        .filter(|f| f.attr.positional.is_some())
+        .collect::<Vec<_>>();
+    let (positional_init, positional_arg) = match positional.is_empty() {
+        true => (None, None),
+        false => {
+            let count = positional.len();
+            let accepted = keys
+                .iter()
+                .map(|key| key.as_str())
+                .chain(renames_old.iter().map(|old| old.as_str()))
+                .collect::<Vec<_>>();
+            let slots = positional.iter().enumerate().map(|(idx, f)| {
+                let key = &f.key;
+                let var = &f.var;
+                let ty = match f.shape {
+                    Shape::Optional(ty) | Shape::Value(ty) => ty,
+                    Shape::Flag => unreachable!(),
+                };
+                quote! {
+                    #idx => {
+                        keys.claim(&#syn::Ident::new(#key, input.span()))?;
+                        #var = #some(input.parse::<#ty>()?);
+                    }
+                }
+            });
+            let init = quote! {
+                let mut keyword = false;
+                let mut position = 0usize;
+            };
+            let arg = quote! {
+                let is_key = match <#syn::Ident as #syn::ext::IdentExt>::parse_any(&input.fork()) {
+                    #ok(ident) => {
+                        keyword || position == #count || [#(#accepted),*].contains(&ident.to_string().as_str())
+                    }
+                    #err(_) => false,
+                };
+                if !is_key {
+                    if keyword {
+                        return #err(#syn::Error::new(
+                            input.span(),
+                            "Positional Arguments Must Precede Keyword Arguments",
+                        ));
+                    }
+                    match position {
+                        #(#slots)*
+                        _ => return #err(#syn::Error::new(
+                            input.span(),
+                            "Unexpected Positional Argument",
+                        )),
+                    }
+                    position += 1;
+                    if !#hizli::parse_separator::<#syn::token::Comma>(input)? {
+                        break;
+                    }
+                    continue;
+                }
+                keyword = true;
+            };
+            (Some(init), Some(arg))
+        }
+    };
+
+    let vars = parsed.iter().map(|f| &f.var);
+    let required = parsed
+        .iter()
+        .filter(|f| f.attr.required.is_some())
+        .map(|f| &f.key);
+
+    let arms = parsed.iter().map(|f| {
+        let key = &f.key;
+        let var = &f.var;
+        let value = match f.shape {
+            Shape::Flag => quote! {
+                match input.peek(#syn::token::Eq) {
+                    true => {
+                        input.parse::<#syn::token::Eq>()?;
+                        input.parse::<#syn::LitBool>()?.value
+                    }
+                    false => true,
+                }
+            },
+            Shape::Optional(ty) | Shape::Value(ty) if f.attr.nested.is_some() => quote! {
+                match input.peek(#syn::token::Paren) {
+                    true => {
+                        let content;
+                        #syn::parenthesized!(content in input);
+                        content.parse::<#ty>()?
+                    }
+                    false => #syn::parse::Parser::parse2(
+                        <#ty as #syn::parse::Parse>::parse,
+                        #hizli::proc_macro2::TokenStream::new(),
+                    ).map_err(|err| #syn::Error::new(key.span(), err))?,
+                }
+            },
+            Shape::Optional(ty) | Shape::Value(ty) => quote! {{
+                input.parse::<#syn::token::Eq>()?;
+                input.parse::<#ty>()?
+            }},
+        };
+        quote! {
+            #key => #var = #some(#value),
+        }
+    });
+
+    let inits = fields.iter().map(|f| {
+        let field_id = f.ident;
+        let var = &f.var;
+        // `keys.require` reports every missing required key at once, so this error is a
+        // fallback that keeps the generated code free of panics.
+        let default = match f.attr.required {
+            Some(_) => {
+                let msg = format!("Missing Required Key `{}`", f.key);
+                quote! { return #err(#syn::Error::new(input.span(), #msg)) }
+            }
+            None => f.default_expr(&fragments),
+        };
+        match (&f.attr.skip, &f.shape) {
+            _ if f.attr.unknown.is_some() => quote! { #field_id: unknown },
+            _ if f.attr.warnings.is_some() => quote! { #field_id: warnings },
+            _ if f.attr.passthrough.is_some() => quote! { #field_id: passthrough },
+            _ if f.attr.key_set.is_some() => {
+                quote! { #field_id: #clone(&keys) }
+            }
+            (Some(_), _) => quote! { #field_id: #default },
+            (None, Shape::Optional(_)) => quote! {
+                #field_id: match #var {
+                    #some(value) => #some(value),
+                    #none => #default,
+                }
+            },
+            (None, _) => quote! {
+                #field_id: match #var {
+                    #some(value) => value,
+                    #none => #default,
+                }
+            },
+        }
+    });
+
+    let aliases = (!attr.alias.is_empty()).then(|| {
+        let alias = &attr.alias;
+        quote! { const ALIASES: &[&str] = &[#(#alias),*]; }
+    });
+
+    let parse = quote! {
+        let mut keys = #hizli::KeySet::default();
+        #collect
+        #collect_passthrough
+        #warnings
+        #(let mut #vars = #none;)*
+
+        #positional_init
+        while !input.is_empty() {
+            #positional_arg
+            let key = <#syn::Ident as #syn::ext::IdentExt>::parse_any(input)?;
+            let key = match key.to_string().as_str() {
+                #(#renames)*
+                _ => key,
+            };
+            keys.claim(&key)?;
+
+            match key.to_string().as_str() {
+                #(#arms)*
+                #fallback
+            }
+
+            if !#hizli::parse_separator::<#syn::token::Comma>(input)? {
+                break;
+            }
+        }
+        keys.require(input.span(), &[#(#required),*])?;
+    };
+    let inits = inits.collect::<Vec<_>>();
+
+    let inherit = attr.inherit.as_ref().map(|_| {
+        // `explicit` resolves flags from the parsed key, so a field-level `flag = false`
+        // overrides a container-level `true`.
+        let inherits = |this: TokenStream, explicit: bool| {
+            let entries = fields.iter().map(|f| {
+                let field_id = f.ident;
+                let var = &f.var;
+                match (&f.attr.skip, &f.shape) {
+                    (None, Shape::Flag) if explicit => quote! {
+                        #field_id: match #var {
+                            #some(value) => value,
+                            #none => #this.#field_id || parent.#field_id,
+                        }
+                    },
+                    (None, Shape::Flag) => {
+                        quote! { #field_id: #this.#field_id || parent.#field_id }
+                    }
+                    (None, Shape::Optional(_)) => quote! {
+                        #field_id: match #this.#field_id {
+                            #some(value) => #some(value),
+                            #none => #clone(&parent.#field_id),
+                        }
+                    },
+                    _ => quote! { #field_id: #this.#field_id },
+                }
+            });
+            quote! { Self { #(#entries),* } }
+        };
+        let inherit = inherits(quote! { self }, false);
+        let parse_inherit = inherits(quote! { attr }, true);
+        quote! {
+            fn inherit(self, parent: &Self) -> Self {
+                #inherit
+            }
+
+            fn parse_inherit(input: #syn::parse::ParseStream, parent: &Self) -> #syn::Result<Self> {
+                #parse
+                let attr = Self {
+                    #(#inits),*
+                };
+                #ok(#parse_inherit)
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        #allow
+        impl #impl_gen #hizli::NsAttr for #ident #type_gen #where_cl {
+            const NS: &str = #ns;
+            const KEYS: &[&str] = &[#(#keys),*];
+            #aliases
+
+            #inherit
+        }
+
+        #[automatically_derived]
+        #allow
+        impl #impl_gen #syn::parse::Parse for #ident #type_gen #where_cl {
+            fn parse(input: #syn::parse::ParseStream) -> #syn::Result<Self> {
+                #parse
+
+                #ok(Self {
+                    #(#inits),*
+                })
+            }
+        }
+    })
+}
+
+/// Returns `true` if a field collects the key selected by `get`, rejecting more than one such field.
+fn collector(fields: &[KeyField], get: fn(&HizliAttr) -> Option<&Ident>) -> Result<bool> {
+    let mut found = fields.iter().filter_map(|f| get(&f.attr));
+    match (found.next(), found.next()) {
+        (_, Some(second)) => Err(Error::new(
+            second.span(),
+            format!("Only One Field May Collect `{second}`"),
+        )),
+        (first, None) => Ok(first.is_some()),
+    }
+}
@@ -0,0 +1,180 @@
+use hizli_core::Fragments;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{Ident, Result, Type, Variant};
+
+use crate::{
+    attr::{Delimiter, HizliAttr},
+    parse::sum::first_parsed,
+};
+
+/// What `#[hizli(cursor)]` reads from the cursor to choose a variant.
+enum Key {
+    /// A keyword, `Token![fn]`, matched against the next identifier.
+    Keyword(String),
+    /// A single character punctuation, `Token![+]`, matched against the next punctuation.
+    Punct(char),
+    /// A `#[hizli(delimited)]` variant, matched against the next group.
+    Group(Delimiter),
+}
+
+/// Returns the key of `variant`, or `None` when it can only be chosen by peeking, as for
+/// `#[hizli(peek_not)]` variants.
+fn key(variant: &Variant) -> Result<Option<Key>> {
+    let attr = HizliAttr::variant(variant)?;
+    if attr.peek_not.is_some() {
+        return Ok(None);
+    }
+    if let Some(delimited) = attr.delimited {
+        return Ok(Some(Key::Group(delimited)));
+    }
+    let first = match first_parsed(variant)? {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+    if let Some(delimited) = HizliAttr::field(first)?.delimited {
+        return Ok(Some(Key::Group(delimited)));
+    }
+    let mac = match &first.ty {
+        Type::Macro(ty) if ty.mac.path.is_ident("Token") => &ty.mac,
+        _ => return Ok(None),
+    };
+    let tokens: Vec<TokenTree> = mac.tokens.clone().into_iter().collect();
+    Ok(match tokens.as_slice() {
+        [TokenTree::Ident(ident)] if ident != "_" => Some(Key::Keyword(ident.to_string())),
+        [TokenTree::Punct(punct)] => Some(Key::Punct(punct.as_char())),
+        _ => None,
+    })
+}
+
+/// The variants of one run of [`dispatch`], each with the expression constructing it.
+#[derive(Default)]
+struct Run {
+    keywords: Vec<(String, TokenStream)>,
+    puncts: Vec<(char, TokenStream)>,
+    groups: Vec<(Delimiter, TokenStream)>,
+}
+
+impl Run {
+    fn is_empty(&self) -> bool {
+        self.keywords.is_empty() && self.puncts.is_empty() && self.groups.is_empty()
+    }
+
+    /// Adds a variant, unless an earlier variant of the run already claims its key.
+    fn push(&mut self, key: Key, construct: TokenStream) {
+        fn claim<K: PartialEq>(arms: &mut Vec<(K, TokenStream)>, key: K, construct: TokenStream) {
+            if !arms.iter().any(|(claimed, _)| *claimed == key) {
+                arms.push((key, construct));
+            }
+        }
+        match key {
+            Key::Keyword(keyword) => claim(&mut self.keywords, keyword, construct),
+            Key::Punct(punct) => claim(&mut self.puncts, punct, construct),
+            Key::Group(delimiter) => claim(&mut self.groups, delimiter, construct),
+        }
+    }
+
+    /// Returns the statements returning the variant matching the cursor, if any.
+    ///
+    /// Identifiers, punctuation and groups never overlap, so each kind is matched on its own.
+    /// Keywords are compared with `Ident: PartialEq<str>`, without rendering the identifier.
+    fn to_tokens(&self, proc_macro2: &TokenStream, fragments: &Fragments) -> TokenStream {
+        let some = fragments.some();
+        let keywords = (!self.keywords.is_empty()).then(|| {
+            let arms = self.keywords.iter().map(|(keyword, construct)| {
+                quote! {
+                    if __hizli_ident == #keyword {
+                        return #construct;
+                    }
+                }
+            });
+            quote! {
+                if let #some(__hizli_ident) = &__hizli_ident {
+                    #(#arms)*
+                }
+            }
+        });
+        let puncts = (!self.puncts.is_empty()).then(|| {
+            let arms = self.puncts.iter().map(|(punct, construct)| {
+                quote! { #some(#punct) => return #construct, }
+            });
+            quote! {
+                match __hizli_punct {
+                    #(#arms)*
+                    _ => {}
+                }
+            }
+        });
+        let groups = self.groups.iter().map(|(delimiter, construct)| {
+            let name = Ident::new(delimiter.token_name(), Span::call_site());
+            quote! {
+                if __hizli_cursor.group(#proc_macro2::Delimiter::#name).is_some() {
+                    return #construct;
+                }
+            }
+        });
+        quote! {
+            #keywords
+            #puncts
+            #(#groups)*
+        }
+    }
+}
+
+/// The body of `parse` for `#[hizli(cursor)]`, before the fallback error.
+///
+/// The next token is read from `input.cursor()` once, then consecutive keyword, punctuation and
+/// delimited variants are chosen by a `match` on it instead of one `input.peek` each. Any other
+/// variant still peeks its first field, and variants keep being tried in declaration order.
+pub fn dispatch(
+    variants: &[(&Variant, TokenStream, TokenStream)],
+    proc_macro2: &TokenStream,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    let mut body = Vec::new();
+    let mut run = Run::default();
+    let (mut cursor, mut ident, mut punct) = (false, false, false);
+    for (variant, peek, construct) in variants {
+        match key(variant)? {
+            Some(key) => {
+                cursor = true;
+                ident |= matches!(key, Key::Keyword(_));
+                punct |= matches!(key, Key::Punct(_));
+                run.push(key, construct.clone());
+            }
+            None => {
+                if !run.is_empty() {
+                    body.push(std::mem::take(&mut run).to_tokens(proc_macro2, fragments));
+                }
+                body.push(quote! {
+                    if #peek {
+                        return #construct;
+                    }
+                });
+            }
+        }
+    }
+    if !run.is_empty() {
+        body.push(run.to_tokens(proc_macro2, fragments));
+    }
+
+    let cursor = cursor.then(|| quote! { let __hizli_cursor = input.cursor(); });
+    let ident = ident.then(|| {
+        quote! {
+            let __hizli_ident = __hizli_cursor.ident().map(|(ident, _)| ident);
+        }
+    });
+    let punct = punct.then(|| {
+        quote! {
+            let __hizli_punct = __hizli_cursor.punct().map(|(punct, _)| punct.as_char());
+        }
+    });
+
+    Ok(quote! {
+        #cursor
+        #ident
+        #punct
+
+        #(#body)*
+    })
+}
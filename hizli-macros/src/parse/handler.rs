@@ -1,25 +1,125 @@
-use hizli_core::StructEnumOnly;
+use hizli_core::{StructEnumOnly, anon_const_scope, derive_span};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Result};
+use syn::{DeriveInput, Error, Result};
 
-use crate::parse::{product::product, sum::sum};
+use crate::{
+    attr::HizliAttr,
+    parse::{
+        keywords,
+        product::{Call, Context, product},
+        sum::sum,
+        unordered::unordered,
+    },
+};
 
 pub fn handler(input: DeriveInput) -> Result<TokenStream> {
+    let attr = HizliAttr::container(&input.attrs)?;
+    let allow = attr.allow_attr();
+    let span = derive_span();
+    let syn = attr.syn();
+    let fragments = attr.fragments();
+
     let ident = input.ident;
     let (impl_gen, type_gen, where_cl) = input.generics.split_for_impl();
+    let where_cl = attr.where_clause(where_cl);
+    let keywords = match attr.keywords {
+        Some(_) => Some(keywords::keywords(&keywords::collect(&input.data)?, &syn)),
+        None => None,
+    };
 
-    let block = match StructEnumOnly::try_new(input.data, "Parse")? {
-        StructEnumOnly::Struct(s) => product(s),
-        StructEnumOnly::Enum(e) => sum(e, &ident.to_string())?,
+    let context = attr.context.as_ref().map(|_| Context {
+        hizli: attr.hizli(),
+        name: ident.to_string(),
+    });
+    let hizli = attr.hizli();
+    let call = Call {
+        hizli: &hizli,
+        context: context.as_ref(),
+        spanned: attr.spanned.is_some(),
     };
 
-    Ok(quote! {
+    let (block, helpers) = match StructEnumOnly::try_new_at(input.data, "Parse", span)? {
+        StructEnumOnly::Struct(s) => match attr.unordered.is_some() {
+            true if attr.delimited.is_some() => {
+                return Err(Error::new(
+                    span,
+                    "Key `delimited` Conflicts With `unordered`",
+                ));
+            }
+            true => (
+                unordered(&s, &attr, context.as_ref(), span, &fragments)?,
+                Vec::new(),
+            ),
+            false => (
+                product(&s, attr.delimited, call, &syn, &fragments)?,
+                Vec::new(),
+            ),
+        },
+        StructEnumOnly::Enum(e) => {
+            if let Some(unordered) = &attr.unordered {
+                return Err(Error::new(
+                    unordered.span(),
+                    "#[hizli(unordered)] Is Only Supported On Structs",
+                ));
+            }
+            if attr.delimited.is_some() {
+                return Err(Error::new(
+                    span,
+                    "#[hizli(delimited)] Is Only Supported On Structs And Variants",
+                ));
+            }
+            let sum = sum(&e, &ident, &attr, span, &fragments)?;
+            (sum.body, sum.helpers)
+        }
+    };
+
+    let parse_impl = quote! {
         #[automatically_derived]
-        impl #impl_gen ::syn::parse::Parse for #ident #type_gen #where_cl {
-            fn parse(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+        #allow
+        impl #impl_gen #syn::parse::Parse for #ident #type_gen #where_cl {
+            fn parse(input: #syn::parse::ParseStream) -> #syn::Result<Self> {
                 #block
             }
         }
+    };
+
+    let keywords = keywords.map(|keywords| {
+        quote! {
+            #[automatically_derived]
+            #allow
+            impl #impl_gen #ident #type_gen #where_cl {
+                #keywords
+            }
+        }
+    });
+
+    // Helpers are free functions in the scope of the impl, so they are neither visible to the
+    // crate of the input nor able to collide with its methods.
+    let parse_impl = match helpers.is_empty() {
+        true => parse_impl,
+        false => {
+            let helpers = helpers.iter().map(|(helper, construct)| {
+                quote! {
+                    #[inline]
+                    #allow
+                    fn #helper #impl_gen(
+                        input: #syn::parse::ParseStream,
+                    ) -> #syn::Result<#ident #type_gen> #where_cl {
+                        #construct
+                    }
+                }
+            });
+            anon_const_scope(quote! {
+                #(#helpers)*
+
+                #parse_impl
+            })
+        }
+    };
+
+    Ok(quote! {
+        #keywords
+        #parse_impl
     })
 }
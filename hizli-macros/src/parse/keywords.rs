@@ -0,0 +1,74 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{Data, Error, Field, Ident, Result, Type};
+
+use crate::attr::HizliAttr;
+
+/// Returns the keyword parsed by a field of type `ty`, if any.
+///
+/// `Token![kw]` is always a keyword. Types created by `syn::custom_keyword!` cannot be told apart
+/// from other types, so they count only when the field is marked `#[hizli(keyword)]`, and are then
+/// named after the last segment of their path, as in `kw::select`.
+fn keyword(ty: &Type, marker: Option<&Ident>) -> Result<Option<String>> {
+    match ty {
+        Type::Macro(ty) if ty.mac.path.is_ident("Token") => {
+            let tokens: Vec<TokenTree> = ty.mac.tokens.clone().into_iter().collect();
+            Ok(match tokens.as_slice() {
+                [TokenTree::Ident(ident)] if ident != "_" => Some(ident.to_string()),
+                _ => None,
+            })
+        }
+        Type::Group(ty) => keyword(&ty.elem, marker),
+        Type::Paren(ty) => keyword(&ty.elem, marker),
+        _ => match (ty, marker) {
+            (_, None) => Ok(None),
+            (Type::Path(ty), Some(_)) if ty.qself.is_none() => {
+                let last = ty.path.segments.last().expect("path has a segment");
+                Ok(Some(last.ident.to_string()))
+            }
+            (_, Some(marker)) => Err(Error::new(
+                marker.span(),
+                "Key `keyword` Requires A `custom_keyword!` Type",
+            )),
+        },
+    }
+}
+
+/// Returns the keywords parsed by the fields of `data` that are not `#[hizli(skip)]`, in
+/// declaration order and without duplicates.
+pub fn collect(data: &Data) -> Result<Vec<String>> {
+    let fields: Vec<&Field> = match data {
+        Data::Struct(s) => s.fields.iter().collect(),
+        Data::Enum(e) => e.variants.iter().flat_map(|v| v.fields.iter()).collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut keywords = Vec::new();
+    for field in fields {
+        let attr = HizliAttr::field(field)?;
+        if attr.skip.is_some() {
+            continue;
+        }
+        match keyword(&field.ty, attr.keyword.as_ref())? {
+            Some(keyword) if !keywords.contains(&keyword) => keywords.push(keyword),
+            _ => {}
+        }
+    }
+    Ok(keywords)
+}
+
+/// The items of `#[hizli(keywords)]`: the `KEYWORDS` table and `is_keyword`, to be placed in an
+/// inherent impl of the input.
+pub fn keywords(keywords: &[String], syn: &TokenStream) -> TokenStream {
+    quote! {
+        /// The keywords parsed by this type, in declaration order.
+        pub const KEYWORDS: &'static [&'static str] = &[#(#keywords),*];
+
+        /// Returns `true` if `ident` is one of [`Self::KEYWORDS`].
+        ///
+        /// Raw identifiers such as `r#select` are never keywords.
+        pub fn is_keyword(ident: &#syn::Ident) -> bool {
+            Self::KEYWORDS.iter().any(|keyword| ident == keyword)
+        }
+    }
+}
@@ -1,4 +1,7 @@
+pub mod dispatch;
 pub mod handler;
+pub mod keywords;
 pub mod product;
 pub mod sum;
 pub mod sum_expected_one_of;
+pub mod unordered;
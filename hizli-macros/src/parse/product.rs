@@ -1,23 +1,379 @@
-use hizli_core::FieldType;
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{DataStruct, Field};
+use hizli_core::{FieldType, Fragments, collect_all};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    DataStruct, Error, Field, Fields, GenericArgument, Ident, Member, PathArguments, Result, Type,
+    ext::IdentExt, spanned::Spanned,
+};
 
-pub fn init(field: &Field) -> TokenStream {
-    match field.ident.as_ref() {
-        Some(id) => quote! { #id: input.parse()? },
-        None => quote! { input.parse()? },
+use crate::{
+    attr::{Delimiter, HizliAttr},
+    ns_attr::field::{Shape, shape},
+};
+
+/// The `#[hizli(context)]` of a derived `Parse`: the path to `hizli` and the name of the type or
+/// variant whose fields are parsed.
+pub struct Context {
+    pub hizli: TokenStream,
+    pub name: String,
+}
+
+impl Context {
+    /// Returns `call?`, prefixing its error with the field `member` when `context` is set.
+    pub fn try_call(context: Option<&Self>, call: TokenStream, member: &Member) -> TokenStream {
+        let context = match context {
+            Some(context) => context,
+            None => return quote! { #call? },
+        };
+        let member = match member {
+            Member::Named(id) => id.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let hizli = &context.hizli;
+        let msg = format!("While Parsing Field `{member}` Of `{}`", context.name);
+        quote! { #call.map_err(|err| #hizli::error_context(err, #msg))? }
+    }
+}
+
+/// How a derived `Parse` reads a field with `input.parse()`: through the `#[hizli(context)]` of
+/// the input, if any, and spanned at the field type with `#[hizli(spanned)]`.
+#[derive(Clone, Copy)]
+pub struct Call<'a> {
+    pub hizli: &'a TokenStream,
+    pub context: Option<&'a Context>,
+    pub spanned: bool,
+}
+
+impl Call<'_> {
+    /// Returns `input.parse()?` reading a field of type `ty`, with `#ty` as its turbofish when
+    /// `turbofish` is set.
+    ///
+    /// With `#[hizli(spanned)]` the call is spanned at `ty`, so a field type that does not
+    /// implement `Parse` is reported at the field instead of at the derive.
+    pub fn parse(self, ty: &Type, turbofish: bool, member: &Member) -> TokenStream {
+        let span = match self.spanned {
+            true => ty.span(),
+            false => Span::call_site(),
+        };
+        let call = match turbofish {
+            true => quote_spanned! {span=> input.parse::<#ty>() },
+            false => quote_spanned! {span=> input.parse() },
+        };
+        Context::try_call(self.context, call, member)
+    }
+}
+
+/// Returns the expression producing `field`, where `delimited` is the innermost delimiter the
+/// field is parsed in:
+/// - `#[hizli(skip)]`: its default,
+/// - `#[hizli(outer)]`: the outer attributes of the input,
+/// - the token of `delimited`: the delimiter token,
+/// - a `DelimitedSpan` inside `delimited`: the span of the delimiter,
+/// - `#[hizli(separator = ...)]`: the separated elements in the rest of the input,
+/// - otherwise: `input.parse()`.
+fn expr(
+    field: &Field,
+    member: &Member,
+    attr: &HizliAttr,
+    delimited: Option<Delimiter>,
+    call: Call,
+    syn: &TokenStream,
+    fragments: &Fragments,
+) -> TokenStream {
+    match (&attr.skip, &attr.default) {
+        (Some(_), Some(default)) => default.expr(fragments),
+        (Some(_), None) => fragments.default_expr(),
+        (None, _) if attr.outer.is_some() => Context::try_call(
+            call.context,
+            quote! { #syn::Attribute::parse_outer(input) },
+            member,
+        ),
+        (None, _) if delimited.is_some_and(|delimited| delimited.is_token(&field.ty)) => {
+            quote! { __hizli_delimiter }
+        }
+        (None, _) if delimited.is_some() && Delimiter::is_span(&field.ty) => {
+            let into = fragments.into_path();
+            quote! { #into(__hizli_delimiter) }
+        }
+        (None, _) if attr.separator.is_some() => separated(field, member, attr, call, fragments),
+        (None, _) => call.parse(&field.ty, false, member),
+    }
+}
+
+/// Returns the expression reading a `#[hizli(separator = ...)]` collection from the rest of the
+/// input, collected with `FromIterator` so both `Vec` and `Punctuated` fields are supported.
+///
+/// The separator after the last element is optional, and required with `#[hizli(trailing)]`,
+/// so the input printed by a derived `ToTokens` parses back.
+fn separated(
+    field: &Field,
+    member: &Member,
+    attr: &HizliAttr,
+    call: Call,
+    fragments: &Fragments,
+) -> TokenStream {
+    let separator = &attr.separator;
+    let hizli = call.hizli;
+    let vec = fragments.vec();
+    let root = fragments.root();
+    // The element type is named so that `FromIterator` is not ambiguous for `Punctuated`, which
+    // is also built from its `Pair`s.
+    let item = match element(&field.ty) {
+        Some(ty) => call.parse(ty, true, member),
+        None => call.parse(&field.ty, false, member),
+    };
+    let separate = match attr.trailing {
+        Some(_) => quote! { input.parse::<#separator>()?; },
+        None => quote! {
+            if !#hizli::parse_separator::<#separator>(input)? {
+                break;
+            }
+        },
+    };
+    quote! {{
+        let mut items = #vec::new();
+        while !input.is_empty() {
+            items.push(#item);
+            #separate
+        }
+        #root::iter::FromIterator::from_iter(items)
+    }}
+}
+
+/// Returns the first type argument of `ty`, the element type of a `Vec` or `Punctuated`.
+fn element(ty: &Type) -> Option<&Type> {
+    let last = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Wraps `expr` so that parsing fails when the field is followed by its `#[hizli(peek_not = ...)]`
+/// token.
+fn peek_not(expr: TokenStream, attr: &HizliAttr, fragments: &Fragments) -> Result<TokenStream> {
+    let ty = match &attr.peek_not {
+        Some(ty) => ty,
+        None => return Ok(expr),
+    };
+    if attr.skip.is_some() {
+        return Err(Error::new_spanned(
+            ty,
+            "Key `peek_not` Conflicts With `skip`",
+        ));
     }
+    let msg = format!("Unexpected `{}`", token_name(ty));
+    let err = fragments.err();
+    Ok(quote! {{
+        let __hizli_value = #expr;
+        if input.peek(#ty) {
+            return #err(input.error(#msg));
+        }
+        __hizli_value
+    }})
 }
 
-pub fn product(s: DataStruct) -> TokenStream {
-    let field_type = FieldType::new(&s.fields);
+/// Returns how a `peek_not` type reads in messages, the token of `Token![...]` or the type.
+fn token_name(ty: &Type) -> String {
+    match ty {
+        Type::Macro(ty) if ty.mac.path.is_ident("Token") => {
+            ty.mac.tokens.to_string().replace(' ', "")
+        }
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+/// Consecutive fields of a field-level `#[hizli(delimited = ...)]`, parsed inside one delimiter.
+struct Group {
+    delimiter: Delimiter,
+    lets: Vec<TokenStream>,
+    vars: Vec<Ident>,
+}
+
+impl Group {
+    /// Returns the statement opening the delimiter, parsing the fields of the group in it and
+    /// binding them to their locals outside of it.
+    fn close(self, syn: &TokenStream) -> TokenStream {
+        let Self {
+            delimiter,
+            lets,
+            vars,
+        } = self;
+        let open = delimiter.open(syn);
+        quote! {
+            let (#(#vars,)*) = {
+                #open
+                #(#lets)*
+                (#(#vars,)*)
+            };
+        }
+    }
+}
+
+/// Returns the statements parsing `fields` and the initializer built from them.
+///
+/// Fields are parsed inline in the initializer, unless one of them is `#[hizli(requires = ...)]`
+/// or `#[hizli(delimited = ...)]`. Every field is then bound to a local first, in declaration
+/// order, so the dependent field is only parsed when the `Option` field it requires is `Some`,
+/// and consecutive fields with the same delimiter are parsed inside it.
+pub fn fields(
+    fields: &Fields,
+    delimited: Option<Delimiter>,
+    call: Call,
+    syn: &TokenStream,
+    fragments: &Fragments,
+) -> Result<(TokenStream, TokenStream)> {
+    let field_type = FieldType::new(fields);
+    let attrs = collect_all(fields.iter().map(HizliAttr::field))?;
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| match &field.ident {
+            Some(id) => Member::Named(id.clone()),
+            None => Member::Unnamed(idx.into()),
+        });
+
+    if attrs
+        .iter()
+        .all(|attr| attr.requires.is_none() && attr.delimited.is_none())
+    {
+        let inits = collect_all(fields.iter().zip(members).zip(&attrs).map(
+            |((field, member), attr)| {
+                let expr = expr(field, &member, attr, delimited, call, syn, fragments);
+                let expr = peek_not(expr, attr, fragments)?;
+                Ok(match field.ident.as_ref() {
+                    Some(id) => quote! { #id: #expr },
+                    None => expr,
+                })
+            },
+        ))?;
+        return Ok((TokenStream::new(), field_type.wrap(quote! { #(#inits),* })));
+    }
 
-    let inits = s.fields.iter().map(init);
+    let mut lets = Vec::with_capacity(attrs.len());
+    let mut inits = Vec::with_capacity(attrs.len());
+    let mut parsed: Vec<(Member, &Field, Ident)> = Vec::with_capacity(attrs.len());
+    let mut group: Option<Group> = None;
+    for (idx, ((field, member), attr)) in fields.iter().zip(members).zip(&attrs).enumerate() {
+        let var = format_ident!("__hizli_field_{}", idx);
 
-    let init = field_type.wrap(quote! { #(#inits),* });
+        // Skipped fields are not read from the input, so they never end a group.
+        let current = group.as_ref().map(|group| group.delimiter);
+        let field_delimited = match attr.skip {
+            Some(_) => current,
+            None => attr.delimited,
+        };
+        if current != field_delimited {
+            lets.extend(group.take().map(|group| group.close(syn)));
+            group = field_delimited.map(|delimiter| Group {
+                delimiter,
+                lets: Vec::new(),
+                vars: Vec::new(),
+            });
+        }
 
-    quote! {
-        ::core::result::Result::Ok(Self #init)
+        let expr = match &attr.requires {
+            Some(requires) => {
+                let parsed = &parsed;
+                requires_expr(field, &member, attr, requires, parsed, call, fragments)?
+            }
+            None => {
+                let delimited = field_delimited.or(delimited);
+                expr(field, &member, attr, delimited, call, syn, fragments)
+            }
+        };
+        let expr = peek_not(expr, attr, fragments)?;
+        let stmt = quote! { let #var = #expr; };
+        match &mut group {
+            Some(group) => {
+                group.lets.push(stmt);
+                group.vars.push(var.clone());
+            }
+            None => lets.push(stmt),
+        }
+        inits.push(match &field.ident {
+            Some(id) => quote! { #id: #var },
+            None => quote! { #var },
+        });
+        parsed.push((member, field, var));
     }
+    lets.extend(group.map(|group| group.close(syn)));
+
+    Ok((
+        quote! { #(#lets)* },
+        field_type.wrap(quote! { #(#inits),* }),
+    ))
+}
+
+/// Returns the expression parsing a `#[hizli(requires = ...)]` field when the field it requires,
+/// one of the `parsed` fields, is `Some`.
+fn requires_expr(
+    field: &Field,
+    member: &Member,
+    attr: &HizliAttr,
+    requires: &Member,
+    parsed: &[(Member, &Field, Ident)],
+    call: Call,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    let span = match requires {
+        Member::Named(id) => id.span(),
+        Member::Unnamed(index) => index.span,
+    };
+    if attr.skip.is_some() {
+        return Err(Error::new(span, "Key `requires` Conflicts With `skip`"));
+    }
+    let ty = match shape(&field.ty) {
+        Shape::Optional(ty) => ty,
+        _ => {
+            return Err(Error::new(
+                span,
+                "Key `requires` Is Only Supported On `Option` Fields",
+            ));
+        }
+    };
+    let var = match parsed.iter().find(|(member, ..)| member == requires) {
+        Some((_, required, var)) if matches!(shape(&required.ty), Shape::Optional(_)) => var,
+        _ => {
+            return Err(Error::new(
+                span,
+                "Key `requires` Must Name An Earlier `Option` Field",
+            ));
+        }
+    };
+
+    let some = fragments.some();
+    let none = fragments.none();
+    let parse = call.parse(ty, true, member);
+    Ok(quote! {
+        match &#var {
+            #some(_) => #some(#parse),
+            #none => #none,
+        }
+    })
+}
+
+pub fn product(
+    s: &DataStruct,
+    delimited: Option<Delimiter>,
+    call: Call,
+    syn: &TokenStream,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    let (lets, init) = fields(&s.fields, delimited, call, syn, fragments)?;
+
+    let ok = fragments.ok();
+    let open = delimited.map(|delimited| delimited.open(syn));
+
+    Ok(quote! {
+        #open
+        #lets
+        #ok(Self #init)
+    })
 }
@@ -1,48 +1,234 @@
-use hizli_core::FieldType;
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{DataEnum, Error, Result, Variant};
+use hizli_core::Fragments;
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use syn::{DataEnum, Error, Field, Ident, Result, Variant};
 
-use crate::parse::{product::init, sum_expected_one_of::sum_expected_one_of};
+use crate::{
+    attr::HizliAttr,
+    parse::{
+        dispatch::dispatch,
+        product::{Call, Context, fields},
+        sum_expected_one_of::sum_expected_one_of,
+    },
+};
 
-fn branch(variant: &Variant) -> Result<TokenStream> {
+/// Returns the first field that is not `#[hizli(skip)]`, which is the one peeked for the variant.
+pub fn first_parsed(variant: &Variant) -> Result<Option<&Field>> {
+    for field in variant.fields.iter() {
+        if !HizliAttr::is_skipped(field)? {
+            return Ok(Some(field));
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the peek of `variant` and the expression constructing it.
+///
+/// A `#[hizli(delimited)]` variant peeks its delimiter and parses its fields inside it, as does a
+/// variant whose first field is in a field-level `#[hizli(delimited)]` group.
+/// A `#[hizli(peek_not = ...)]` variant also requires the token after its first field not to
+/// match, parsing the first field on a fork as it may span several tokens, such as `Token![::]`.
+///
+/// The variant is constructed through `path`, `Self` or the name of the enum.
+fn branch(
+    variant: &Variant,
+    path: &TokenStream,
+    call: Call,
+    syn: &TokenStream,
+    fragments: &Fragments,
+) -> Result<(TokenStream, TokenStream)> {
     let ident = &variant.ident;
-    let first = match variant.fields.iter().next() {
-        Some(some) => some,
-        None => {
-            return Err(Error::new(
-                ident.span(),
-                "#[derive(Parse)] Requires At Least One Field",
-            ));
+    let attr = HizliAttr::variant(variant)?;
+    let delimited = attr.delimited;
+    // The type parsed on a fork to reach the token after the first field, `None` when the
+    // first field is one delimited group and that token is simply the second token tree.
+    let mut first_ty = None;
+    let peek = match delimited {
+        Some(delimited) => delimited.token(syn),
+        None => match first_parsed(variant)? {
+            Some(first) if HizliAttr::field(first)?.outer.is_some() => {
+                return Err(Error::new(
+                    ident.span(),
+                    "#[hizli(outer)] Cannot Be The First Field Of A Variant, It Is Not Peekable",
+                ));
+            }
+            Some(first) => match HizliAttr::field(first)?.delimited {
+                Some(delimited) => delimited.token(syn),
+                None => {
+                    first_ty = Some(&first.ty);
+                    first.ty.to_token_stream()
+                }
+            },
+            None => {
+                return Err(Error::new(
+                    ident.span(),
+                    "#[derive(Parse)] Requires At Least One Field",
+                ));
+            }
+        },
+    };
+
+    let (lets, init) = fields(&variant.fields, delimited, call, syn, fragments)?;
+
+    let ok = fragments.ok();
+    let construct = match delimited {
+        Some(delimited) => {
+            let open = delimited.open(syn);
+            quote! {{
+                #open
+                #lets
+                #ok(#path::#ident #init)
+            }}
         }
+        None if lets.is_empty() => quote! { #ok(#path::#ident #init) },
+        None => quote! {{
+            #lets
+            #ok(#path::#ident #init)
+        }},
     };
-    let ty = &first.ty;
 
-    let inits = variant.fields.iter().map(init);
+    let peek = match (&attr.peek_not, first_ty) {
+        (Some(peek_not), Some(ty)) => quote! {
+            input.peek(#peek) && {
+                let fork = input.fork();
+                fork.parse::<#ty>().is_ok() && !fork.peek(#peek_not)
+            }
+        },
+        (Some(peek_not), None) => quote! { input.peek(#peek) && !input.peek2(#peek_not) },
+        (None, _) => quote! { input.peek(#peek) },
+    };
 
-    let init = FieldType::new(&variant.fields).wrap(quote! { #(#inits),* });
+    Ok((peek, construct))
+}
 
-    Ok(quote! {
-        if input.peek(#ty) {
-            return ::core::result::Result::Ok(Self::#ident #init);
-        }
-    })
+/// The body of `parse` and, with `#[hizli(helpers)]`, the helper functions it calls, each named
+/// with the body constructing its variant.
+pub struct Sum {
+    pub body: TokenStream,
+    pub helpers: Vec<(Ident, TokenStream)>,
 }
 
-pub fn sum(e: DataEnum, id: &str) -> Result<TokenStream> {
+pub fn sum(
+    e: &DataEnum,
+    id: &Ident,
+    attr: &HizliAttr,
+    span: Span,
+    fragments: &Fragments,
+) -> Result<Sum> {
     if e.variants.is_empty() {
         return Err(Error::new(
-            e.enum_token.span,
+            span,
             "Cannot #[derive(Parse)] On An Empty Enum. It's Not Constructable At Runtime",
         ));
     }
-    let msg = sum_expected_one_of(&e, id);
 
-    let branches = e.variants.iter().map(branch).collect::<Result<Vec<_>>>()?;
+    let syn = attr.syn();
+    let hizli = attr.hizli();
+    let fork = attr.fork.is_some();
+    let cursor = attr.cursor.is_some();
+    let spanned = attr.spanned.is_some();
+    if let (Some(_), Some(cursor)) = (&attr.fork, &attr.cursor) {
+        return Err(Error::new(
+            cursor.span(),
+            "Key `cursor` Conflicts With `fork`",
+        ));
+    }
+
+    let msg = sum_expected_one_of(e, &id.to_string())?;
+    let err = fragments.err();
+    let fallback = match fork {
+        true => quote! {
+            let mut error = #syn::Error::new(input.span(), #msg);
+            for err in errors {
+                error.combine(err);
+            }
+            #err(error)
+        },
+        false => quote! {
+            #err(#syn::Error::new(input.span(), #msg))
+        },
+    };
+
+    let mut branches = Vec::with_capacity(e.variants.len());
+    let mut helpers = Vec::new();
+    let mut dispatched = Vec::new();
+    for (index, variant) in e.variants.iter().enumerate() {
+        let context = attr.context.as_ref().map(|_| Context {
+            hizli: attr.hizli(),
+            name: format!("{id}::{}", variant.ident),
+        });
+        let call = Call {
+            hizli: &hizli,
+            context: context.as_ref(),
+            spanned,
+        };
+        // Helpers are free functions, where `Self` is not in scope.
+        let path = match attr.helpers {
+            Some(_) => quote! { #id },
+            None => quote! { Self },
+        };
+        let (peek, mut construct) = branch(variant, &path, call, &syn, fragments)?;
+        if let (true, Some(peek_not)) = (fork, HizliAttr::variant(variant)?.peek_not) {
+            return Err(Error::new_spanned(
+                peek_not,
+                "Key `peek_not` Conflicts With `fork`",
+            ));
+        }
+
+        if attr.helpers.is_some() {
+            let helper = format_ident!("__hizli_parse_{index}");
+            helpers.push((helper.clone(), construct));
+            construct = quote! { #helper(input) };
+        }
+
+        if cursor {
+            dispatched.push((variant, peek, construct));
+            continue;
+        }
+
+        branches.push(match fork {
+            true => {
+                let ok = fragments.ok();
+                quote! {
+                    let fork = input.fork();
+                    let result: #syn::Result<Self> = (|| {
+                        let input = &fork;
+                        #construct
+                    })();
+                    match result {
+                        #ok(value) => {
+                            #syn::parse::discouraged::Speculative::advance_to(input, &fork);
+                            return #ok(value);
+                        }
+                        #err(err) => errors.push(err),
+                    }
+                }
+            }
+            false => quote! {
+                if #peek {
+                    return #construct;
+                }
+            },
+        });
+    }
+
+    if cursor {
+        branches.push(dispatch(&dispatched, &attr.proc_macro2(), fragments)?);
+    }
+
+    let errors = fork.then(|| {
+        let vec = fragments.vec();
+        quote! { let mut errors = #vec::new(); }
+    });
+
+    Ok(Sum {
+        body: quote! {
+            #errors
 
-    Ok(quote! {
-        #(#branches)*
+            #(#branches)*
 
-        ::core::result::Result::Err(::syn::Error::new(input.span(), #msg))
+            #fallback
+        },
+        helpers,
     })
 }
@@ -1,12 +1,19 @@
-use std::fmt::Write;
-
 use quote::ToTokens;
-use syn::DataEnum;
+use syn::{DataEnum, Result};
+
+use crate::{attr::HizliAttr, parse::sum::first_parsed};
 
-pub fn sum_expected_one_of(e: &DataEnum, id: &str) -> String {
+pub fn sum_expected_one_of(e: &DataEnum, id: &str) -> Result<String> {
     let mut buf = String::new();
 
-    for field in e.variants.iter().filter_map(|v| v.fields.iter().next()) {
+    for variant in e.variants.iter() {
+        let expected = match HizliAttr::variant(variant)?.delimited {
+            Some(delimited) => delimited.display().to_owned(),
+            None => match first_parsed(variant)? {
+                Some(field) => field.ty.to_token_stream().to_string(),
+                None => continue,
+            },
+        };
         if buf.is_empty() {
             buf.push_str("Error Parsing: ");
             buf.push_str(id);
@@ -14,8 +21,8 @@ pub fn sum_expected_one_of(e: &DataEnum, id: &str) -> String {
         } else {
             buf.push_str(", ");
         }
-        write!(&mut buf, "{}", field.ty.to_token_stream()).unwrap();
+        buf.push_str(&expected);
     }
 
-    buf
+    Ok(buf)
 }
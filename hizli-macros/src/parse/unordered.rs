@@ -0,0 +1,147 @@
+use hizli_core::Fragments;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataStruct, Error, Fields, Member, Result, ext::IdentExt};
+
+use crate::{
+    attr::{DefaultValue, HizliAttr},
+    ns_attr::field::{Shape, shape},
+    parse::product::{Call, Context},
+};
+
+/// The body of `parse` for `#[hizli(unordered)]`, reading `key = value` clauses in any order.
+///
+/// `bool` fields are bare keywords, optionally followed by `= true` or `= false`. Every other key
+/// requires `=` before its value; `Option<T>` fields are `None` when absent and any other field
+/// falls back to its `#[hizli(default)]` or `Default::default()`.
+pub fn unordered(
+    s: &DataStruct,
+    attr: &HizliAttr,
+    context: Option<&Context>,
+    span: Span,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    let fields = match &s.fields {
+        Fields::Named(named) => &named.named,
+        _ => {
+            return Err(Error::new(
+                span,
+                "#[hizli(unordered)] Requires A Struct With Named Fields",
+            ));
+        }
+    };
+
+    let syn = attr.syn();
+    let hizli = attr.hizli();
+
+    let mut vars = Vec::new();
+    let mut keys = Vec::new();
+    let mut arms = Vec::new();
+    let mut inits = Vec::with_capacity(fields.len());
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().expect("named field");
+        let field_attr = HizliAttr::field(field)?;
+        if let Some(requires) = &field_attr.requires {
+            return Err(Error::new_spanned(
+                requires,
+                "Key `requires` Conflicts With `unordered`",
+            ));
+        }
+        if let Some(outer) = &field_attr.outer {
+            return Err(Error::new(
+                outer.span(),
+                "Key `outer` Conflicts With `unordered`",
+            ));
+        }
+        if field_attr.delimited.is_some() {
+            return Err(Error::new(
+                ident.span(),
+                "Key `delimited` Conflicts With `unordered`",
+            ));
+        }
+        let shape = shape(&field.ty);
+
+        let default = match (&field_attr.default, &shape) {
+            (Some(default), _) => default.expr(fragments),
+            (None, Shape::Flag) => quote! { false },
+            (None, Shape::Optional(_)) => fragments.none(),
+            (None, Shape::Value(_)) => DefaultValue::Trait.expr(fragments),
+        };
+        if field_attr.skip.is_some() {
+            inits.push(quote! { #ident: #default });
+            continue;
+        }
+
+        let key = ident.unraw().to_string();
+        let var = format_ident!("__hizli_{}", key);
+        let some = fragments.some();
+        let value = match shape {
+            Shape::Flag => quote! {
+                if input.peek(#syn::token::Eq) {
+                    input.parse::<#syn::token::Eq>()?;
+                    input.parse::<#syn::LitBool>()?.value
+                } else {
+                    true
+                }
+            },
+            Shape::Optional(ty) | Shape::Value(ty) => {
+                let member = Member::Named(ident.clone());
+                let parse = Call {
+                    hizli: &hizli,
+                    context,
+                    spanned: attr.spanned.is_some(),
+                }
+                .parse(ty, true, &member);
+                quote! {
+                    {
+                        input.parse::<#syn::token::Eq>()?;
+                        #parse
+                    }
+                }
+            }
+        };
+        arms.push(quote! {
+            #key => #var = #some(#value),
+        });
+        let none = fragments.none();
+        inits.push(match shape {
+            Shape::Optional(_) if field_attr.default.is_none() => quote! { #ident: #var },
+            Shape::Optional(_) => quote! {
+                #ident: match #var {
+                    #some(value) => #some(value),
+                    #none => #default,
+                }
+            },
+            _ => quote! { #ident: #var.unwrap_or_else(|| #default) },
+        });
+        vars.push(var);
+        keys.push(key);
+    }
+
+    let ok = fragments.ok();
+    let err = fragments.err();
+    let none = fragments.none();
+
+    Ok(quote! {
+        let mut keys = #hizli::KeySet::default();
+        #(let mut #vars = #none;)*
+
+        while !input.is_empty() {
+            let key = <#syn::Ident as #syn::ext::IdentExt>::parse_any(input)?;
+            keys.claim(&key)?;
+
+            match key.to_string().as_str() {
+                #(#arms)*
+                _ => return #err(#hizli::unknown_key(&key, &[#(#keys),*])),
+            }
+
+            if !#hizli::parse_separator::<#syn::token::Comma>(input)? {
+                break;
+            }
+        }
+
+        #ok(Self {
+            #(#inits),*
+        })
+    })
+}
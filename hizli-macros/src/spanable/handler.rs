@@ -1,24 +1,37 @@
-use hizli_core::StructEnumOnly;
+use hizli_core::{StructEnumOnly, derive_span};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, Result};
 
-use crate::spanable::{product::product, sum::sum};
+use crate::{
+    attr::HizliAttr,
+    spanable::{product::product, sum::sum},
+};
 
 pub fn handler(input: DeriveInput) -> Result<TokenStream> {
+    let attr = HizliAttr::container(&input.attrs)?;
+    let allow = attr.allow_attr();
+    let span = derive_span();
+    let proc_macro2 = attr.proc_macro2();
+    let hizli = attr.hizli();
+    let spanned = attr.spanned.is_some();
+
     let ident = input.ident;
 
     let (impl_gen, type_gen, where_cl) = input.generics.split_for_impl();
+    let where_cl = attr.where_clause(where_cl);
 
-    let block = match StructEnumOnly::try_new(input.data, "Spanable")? {
-        StructEnumOnly::Enum(e) => sum(e),
-        StructEnumOnly::Struct(s) => product(s),
+    let block = match StructEnumOnly::try_new_at(input.data, "Spanable", span)? {
+        StructEnumOnly::Enum(e) => sum(&e, spanned, &hizli, &proc_macro2)?,
+        StructEnumOnly::Struct(s) => product(&s, spanned, &hizli, &proc_macro2)?,
     };
 
     Ok(quote! {
         #[automatically_derived]
-        impl #impl_gen #ident #type_gen #where_cl {
-            fn spanable(&self) -> ::proc_macro2::Span {
+        #allow
+        impl #impl_gen #hizli::Spanable for #ident #type_gen #where_cl {
+            #[allow(unused_variables)]
+            fn spanable(&self) -> #proc_macro2::Span {
                 #block
             }
         }
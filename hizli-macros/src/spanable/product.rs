@@ -1,10 +1,37 @@
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::DataStruct;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{DataStruct, Result, Type, spanned::Spanned};
 
-pub fn product(s: DataStruct) -> TokenStream {
-    match s.fields.members().next() {
-        Some(member) => quote! { self.#member.span() },
-        None => quote! { ::proc_macro2::Span::call_site() },
+use crate::attr::HizliAttr;
+
+/// Returns the span of the field referenced by `field`, through `Spanable` when its type
+/// implements it and through `syn::spanned::Spanned` otherwise.
+///
+/// With `#[hizli(spanned)]` the call is spanned at `ty`, so a field type implementing neither is
+/// reported at the field.
+pub fn span_of(field: TokenStream, ty: &Type, spanned: bool, hizli: &TokenStream) -> TokenStream {
+    let span = match spanned {
+        true => ty.span(),
+        false => Span::call_site(),
+    };
+    let call = quote_spanned! {span=> (&#hizli::private::SpanOf(#field)).__hizli_span() };
+    quote! {{
+        use #hizli::private::{ViaSpanable as _, ViaSpanned as _};
+        #call
+    }}
+}
+
+pub fn product(
+    s: &DataStruct,
+    spanned: bool,
+    hizli: &TokenStream,
+    proc_macro2: &TokenStream,
+) -> Result<TokenStream> {
+    for (field, member) in s.fields.iter().zip(s.fields.members()) {
+        if HizliAttr::is_skipped(field)? {
+            continue;
+        }
+        return Ok(span_of(quote! { &self.#member }, &field.ty, spanned, hizli));
     }
+    Ok(quote! { #proc_macro2::Span::call_site() })
 }
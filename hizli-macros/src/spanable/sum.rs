@@ -1,35 +1,52 @@
-use hizli_core::VariantBinding;
+use hizli_core::{VariantBinding, collect_all};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{DataEnum, Variant};
+use syn::{DataEnum, Result, Variant};
 
-fn arm(variant: &Variant) -> TokenStream {
-    let binding = VariantBinding::new(variant);
+use crate::{attr::HizliAttr, spanable::product::span_of};
+
+fn arm(
+    variant: &Variant,
+    spanned: bool,
+    hizli: &TokenStream,
+    proc_macro2: &TokenStream,
+) -> Result<TokenStream> {
+    let binding = VariantBinding::try_new(variant)?;
     let pat = binding.variant_pattern();
 
-    let expr = match binding.field_bindings().iter().next() {
-        Some(some) => {
-            let ident = some.ident();
-            quote! { #ident.span() }
+    let mut expr = quote! { #proc_macro2::Span::call_site() };
+    for fb in binding.iter() {
+        if HizliAttr::field_attrs(fb.attrs())?.skip.is_none() {
+            let ident = fb.ident();
+            expr = span_of(quote! { #ident }, fb.ty(), spanned, hizli);
+            break;
         }
-        None => quote! { ::proc_macro2::Span::call_site() },
-    };
+    }
 
-    quote! {
+    Ok(quote! {
         Self::#pat => #expr
-    }
+    })
 }
 
-pub fn sum(e: DataEnum) -> TokenStream {
+pub fn sum(
+    e: &DataEnum,
+    spanned: bool,
+    hizli: &TokenStream,
+    proc_macro2: &TokenStream,
+) -> Result<TokenStream> {
     if e.variants.is_empty() {
-        return quote! { match *self {} };
+        return Ok(quote! { match *self {} });
     }
 
-    let arms = e.variants.iter().map(arm);
+    let arms = collect_all(
+        e.variants
+            .iter()
+            .map(|variant| arm(variant, spanned, hizli, proc_macro2)),
+    )?;
 
-    quote! {
+    Ok(quote! {
         match self {
             #(#arms),*
         }
-    }
+    })
 }
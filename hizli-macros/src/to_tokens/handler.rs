@@ -0,0 +1,56 @@
+use hizli_core::{StructEnumOnly, derive_span};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Result};
+
+use crate::{
+    attr::HizliAttr,
+    to_tokens::{product::product, sum::sum},
+};
+
+pub fn handler(input: DeriveInput) -> Result<TokenStream> {
+    let attr = HizliAttr::container(&input.attrs)?;
+    let allow = attr.allow_attr();
+    let span = derive_span();
+    let syn = attr.syn();
+    let proc_macro2 = attr.proc_macro2();
+    let quote_path = attr.quote();
+    let fragments = attr.fragments();
+    let spanned = attr.spanned.is_some();
+
+    let ident = input.ident;
+    let (impl_gen, type_gen, where_cl) = input.generics.split_for_impl();
+    let where_cl = attr.where_clause(where_cl);
+
+    let block = match StructEnumOnly::try_new_at(input.data, "ToTokens", span)? {
+        StructEnumOnly::Struct(_) if attr.unordered.is_some() => {
+            return Err(Error::new(
+                span,
+                "#[derive(ToTokens)] Does Not Support `unordered`",
+            ));
+        }
+        StructEnumOnly::Struct(s) => {
+            product(&s, attr.delimited, spanned, &syn, &quote_path, &fragments)?
+        }
+        StructEnumOnly::Enum(e) => {
+            if attr.delimited.is_some() {
+                return Err(Error::new(
+                    span,
+                    "#[hizli(delimited)] Is Only Supported On Structs And Variants",
+                ));
+            }
+            sum(&e, spanned, &syn, &quote_path, &fragments)?
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        #allow
+        impl #impl_gen #quote_path::ToTokens for #ident #type_gen #where_cl {
+            #[allow(unused_variables)]
+            fn to_tokens(&self, tokens: &mut #proc_macro2::TokenStream) {
+                #block
+            }
+        }
+    })
+}
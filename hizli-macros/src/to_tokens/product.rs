@@ -0,0 +1,199 @@
+use hizli_core::{Fragments, StructBinding};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{DataStruct, Error, Ident, Result, spanned::Spanned};
+
+use crate::attr::{Delimiter, HizliAttr};
+
+/// Returns the statements printing `stmts` inside `delimited`, with the span of the delimiter
+/// token `token` when there is one.
+fn surround(
+    delimited: Delimiter,
+    token: Option<TokenStream>,
+    stmts: &[TokenStream],
+    syn: &TokenStream,
+    fragments: &Fragments,
+) -> TokenStream {
+    let token = token.unwrap_or_else(|| {
+        let token = delimited.token(syn);
+        let root = fragments.root();
+        quote! { <#token as #root::default::Default>::default() }
+    });
+    quote! {
+        #token.surround(tokens, |tokens| {
+            #(#stmts)*
+        });
+    }
+}
+
+/// Consecutive fields of a field-level `#[hizli(delimited = ...)]`, printed inside one delimiter.
+struct Group {
+    delimiter: Delimiter,
+    token: Option<TokenStream>,
+    stmts: Vec<TokenStream>,
+}
+
+/// Returns the statements printing the fields of `binding`, each bound to its binding ident.
+///
+/// Fields are printed in declaration order, `#[hizli(skip)]` fields excluded, see [`printed`]. Inside
+/// `#[hizli(delimited = ...)]`, the fields are printed in the delimiter, which reuses the span of
+/// the field holding the delimiter token when there is one. Consecutive fields with the same
+/// field-level `#[hizli(delimited = ...)]` are printed in one delimiter the same way. With
+/// `#[hizli(spanned)]`, each call to `ToTokens` is spanned at the type of its field.
+pub fn fields(
+    binding: &StructBinding,
+    delimited: Option<Delimiter>,
+    spanned: bool,
+    syn: &TokenStream,
+    quote_path: &TokenStream,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    let mut token = None;
+    let mut stmts = Vec::with_capacity(binding.field_bindings().len());
+    let mut group: Option<Group> = None;
+    for fb in binding.iter() {
+        let attr = HizliAttr::field_attrs(fb.attrs())?;
+        let ident = fb.ident();
+        if attr.skip.is_some() {
+            continue;
+        }
+        if let (Some(trailing), None) = (&attr.trailing, &attr.separator) {
+            return Err(Error::new(
+                trailing.span(),
+                "Key `trailing` Requires `separator`",
+            ));
+        }
+
+        if group.as_ref().map(|group| group.delimiter) != attr.delimited {
+            if let Some(group) = group.take() {
+                stmts.push(surround(
+                    group.delimiter,
+                    group.token,
+                    &group.stmts,
+                    syn,
+                    fragments,
+                ));
+            }
+            group = attr.delimited.map(|delimiter| Group {
+                delimiter,
+                token: None,
+                stmts: Vec::new(),
+            });
+        }
+        let (delimited, token, stmts) = match &mut group {
+            Some(group) => (Some(group.delimiter), &mut group.token, &mut group.stmts),
+            None => (delimited, &mut token, &mut stmts),
+        };
+        if let Some(delimited) = delimited {
+            if delimited.is_token(fb.ty()) {
+                *token = Some(quote! { #ident });
+                continue;
+            }
+            if Delimiter::is_span(fb.ty()) {
+                continue;
+            }
+        }
+
+        let span = match spanned {
+            true => fb.ty().span(),
+            false => Span::call_site(),
+        };
+        stmts.push(match (&attr.with, &attr.outer) {
+            (Some(with), _) if attr.separator.is_some() => {
+                return Err(Error::new_spanned(
+                    with,
+                    "Key `with` Conflicts With `separator`",
+                ));
+            }
+            (Some(with), _) => quote! { #with(#ident, tokens); },
+            (None, _) if attr.separator.is_some() => separated(ident, &attr, quote_path, fragments),
+            (None, Some(_)) => {
+                quote_spanned! {span=> #quote_path::TokenStreamExt::append_all(tokens, #ident); }
+            }
+            (None, None) => {
+                quote_spanned! {span=> #quote_path::ToTokens::to_tokens(#ident, tokens); }
+            }
+        });
+    }
+    if let Some(group) = group {
+        stmts.push(surround(
+            group.delimiter,
+            group.token,
+            &group.stmts,
+            syn,
+            fragments,
+        ));
+    }
+
+    Ok(match delimited {
+        Some(delimited) => surround(delimited, token, &stmts, syn, fragments),
+        None => quote! { #(#stmts)* },
+    })
+}
+
+/// Returns the statements printing the elements of a `#[hizli(separator = ...)]` collection with
+/// a separator between each, and after the last one too with `#[hizli(trailing)]`.
+fn separated(
+    ident: &Ident,
+    attr: &HizliAttr,
+    quote_path: &TokenStream,
+    fragments: &Fragments,
+) -> TokenStream {
+    let separator = &attr.separator;
+    let root = fragments.root();
+    let print_separator = quote! {
+        #quote_path::ToTokens::to_tokens(
+            &<#separator as #root::default::Default>::default(),
+            tokens,
+        );
+    };
+    let trailing = attr.trailing.as_ref().map(|_| {
+        quote! {
+            if !__hizli_first {
+                #print_separator
+            }
+        }
+    });
+    quote! {{
+        let mut __hizli_first = true;
+        for __hizli_item in #root::iter::IntoIterator::into_iter(#ident) {
+            if !__hizli_first {
+                #print_separator
+            }
+            __hizli_first = false;
+            #quote_path::ToTokens::to_tokens(__hizli_item, tokens);
+        }
+        #trailing
+    }}
+}
+
+/// Returns the fields of `binding` that are printed, leaving `#[hizli(skip)]` fields out of its
+/// pattern.
+pub fn printed(binding: &StructBinding) -> Result<StructBinding> {
+    let mut skipped = Vec::new();
+    for fb in binding.iter() {
+        if HizliAttr::field_attrs(fb.attrs())?.skip.is_some() {
+            skipped.push(fb.ident().clone());
+        }
+    }
+    let (printed, _) = binding.partition(|fb| !skipped.contains(fb.ident()));
+    Ok(printed)
+}
+
+pub fn product(
+    s: &DataStruct,
+    delimited: Option<Delimiter>,
+    spanned: bool,
+    syn: &TokenStream,
+    quote_path: &TokenStream,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    let binding = printed(&StructBinding::try_new(&s.fields)?)?;
+    let pattern = binding.pattern();
+    let fields = fields(&binding, delimited, spanned, syn, quote_path, fragments)?;
+
+    Ok(quote! {
+        let Self #pattern = self;
+        #fields
+    })
+}
@@ -0,0 +1,41 @@
+use hizli_core::{Fragments, VariantBinding, collect_all};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, Result};
+
+use crate::{
+    attr::HizliAttr,
+    to_tokens::product::{fields, printed},
+};
+
+pub fn sum(
+    e: &DataEnum,
+    spanned: bool,
+    syn: &TokenStream,
+    quote_path: &TokenStream,
+    fragments: &Fragments,
+) -> Result<TokenStream> {
+    if e.variants.is_empty() {
+        return Ok(quote! { match *self {} });
+    }
+
+    let arms = collect_all(e.variants.iter().map(|variant| {
+        let delimited = HizliAttr::variant(variant)?.delimited;
+        let binding = VariantBinding::try_new(variant)?;
+        let printed = printed(binding.struct_binding())?;
+        let ident = binding.ident();
+        let pat = printed.pattern();
+        let fields = fields(&printed, delimited, spanned, syn, quote_path, fragments)?;
+        Ok(quote! {
+            Self::#ident #pat => {
+                #fields
+            }
+        })
+    }))?;
+
+    Ok(quote! {
+        match self {
+            #(#arms)*
+        }
+    })
+}
@@ -1,4 +1,31 @@
-use hizli::Spanable;
+use hizli::{Parse, Spanable};
+
+pub mod ui;
 
 #[derive(Spanable)]
 pub struct X {}
+
+#[derive(Parse, Spanable)]
+#[hizli(crate = ::hizli)]
+pub struct Pair {
+    #[hizli(skip)]
+    pub count: usize,
+    pub left: hizli::syn::Ident,
+    pub comma: hizli::syn::Token![,],
+    pub right: hizli::syn::Ident,
+}
+
+#[derive(Parse, Spanable)]
+#[hizli(crate = ::hizli, bound = "T: hizli::syn::parse::Parse + hizli::syn::spanned::Spanned")]
+pub enum Leaf<T> {
+    Str(hizli::syn::LitStr),
+    Other(#[hizli(skip)] Option<T>, hizli::syn::Ident),
+}
+
+#[derive(Parse, Spanable)]
+#[hizli(crate = ::hizli)]
+pub struct Array<const N: usize> {
+    pub ident: hizli::syn::Ident,
+    #[hizli(skip)]
+    pub items: Option<[u8; N]>,
+}
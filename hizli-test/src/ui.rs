@@ -0,0 +1,67 @@
+//! Helpers for testing the diagnostics of hizli-based macros.
+//!
+//! Compile-fail fixtures live under `tests/ui/<group>/`, one `*.rs` file per case next to the
+//! `*.stderr` file holding its expected output. Run with `TRYBUILD=overwrite` to (re)generate
+//! the expected output; `trybuild` normalizes paths so the files are portable.
+//!
+//! Errors returned in-process, such as from [`NsAttr`](`hizli::NsAttr`) parsing, can be checked
+//! against both their message and the source text they point at with [`assert_error`].
+
+use std::path::Path;
+
+use hizli::syn::Error;
+
+/// Runs every `tests/ui/<group>/*.rs` fixture of the crate at `manifest_dir`,
+/// expecting each to fail to compile with the output in its `*.stderr` file.
+///
+/// Example:
+/// ```ignore
+/// #[test]
+/// fn ui() {
+///     hizli_test::ui::compile_fail(env!("CARGO_MANIFEST_DIR"), "ns_attr");
+/// }
+/// ```
+pub fn compile_fail(manifest_dir: &str, group: &str) {
+    trybuild::TestCases::new().compile_fail(fixtures(manifest_dir, group));
+}
+
+/// Runs every `tests/ui/<group>/*.rs` fixture of the crate at `manifest_dir`,
+/// expecting each to compile and run successfully.
+pub fn pass(manifest_dir: &str, group: &str) {
+    trybuild::TestCases::new().pass(fixtures(manifest_dir, group));
+}
+
+fn fixtures(manifest_dir: &str, group: &str) -> String {
+    Path::new(manifest_dir)
+        .join("tests/ui")
+        .join(group)
+        .join("*.rs")
+        .display()
+        .to_string()
+}
+
+/// Returns the message of every error combined into `err`, in order.
+pub fn messages(err: &Error) -> Vec<String> {
+    err.clone().into_iter().map(|err| err.to_string()).collect()
+}
+
+/// Asserts that `result` failed with `message`, pointing at the source text `spanned`.
+///
+/// The input must come from a string, for example `syn::parse_str`, since tokens built with
+/// `quote!` or `parse_quote!` carry no source locations. Only the first error of a
+/// combined error is checked, see [`messages`] for the others.
+///
+/// # Panics
+/// Panics if `result` is `Ok`, or if the message or the spanned text differ.
+pub fn assert_error<T>(result: hizli::syn::Result<T>, message: &str, spanned: &str) {
+    let err = match result {
+        Ok(_) => panic!("Expected Error `{message}`, Found Ok"),
+        Err(err) => err,
+    };
+    assert_eq!(err.to_string(), message, "Unexpected Error Message");
+    assert_eq!(
+        err.span().source_text().as_deref(),
+        Some(spanned),
+        "Error `{message}` Points At The Wrong Tokens"
+    );
+}
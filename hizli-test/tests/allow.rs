@@ -0,0 +1,37 @@
+#![deny(unused_qualifications)]
+
+use hizli::{
+    NsAttr, Parse, Spanable, assert_parses,
+    syn::{Ident, LitInt, Token},
+};
+
+#[allow(dead_code)]
+#[derive(Parse, Spanable)]
+#[hizli(crate = ::hizli, allow)]
+enum Value {
+    Int(LitInt),
+    Ident(Ident),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, allow = [unused_variables, clippy::needless_question_mark])]
+struct Pair {
+    left: Ident,
+    _eq: Token![=],
+    right: Ident,
+}
+
+#[allow(dead_code)]
+#[derive(NsAttr)]
+#[hizli(ns = "myattr", allow = unused_qualifications)]
+struct MyAttr {
+    skip: bool,
+}
+
+#[test]
+fn allowed_impls_still_work() {
+    assert!(matches!(assert_parses!(Value, { 1 }), Value::Int(_)));
+    assert_eq!(assert_parses!(Pair, { a = b }).right, "b");
+    assert!(assert_parses!(MyAttr, { skip }).skip);
+}
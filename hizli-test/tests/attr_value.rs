@@ -0,0 +1,42 @@
+use hizli::{
+    AttrValue,
+    quote::ToTokens,
+    syn::{Expr, Meta, Path, Type, parse_quote},
+};
+
+#[test]
+fn literals_are_converted() {
+    let expr: Expr = parse_quote!("name");
+    assert_eq!(String::from_expr(&expr).unwrap(), "name");
+
+    let expr: Expr = parse_quote!(42);
+    assert_eq!(u64::from_expr(&expr).unwrap(), 42);
+
+    let meta: Meta = parse_quote!(skip);
+    assert!(bool::from_meta(&meta).unwrap());
+
+    let meta: Meta = parse_quote!(skip = false);
+    assert!(!bool::from_meta(&meta).unwrap());
+}
+
+#[test]
+fn paths_and_types_accept_strings() {
+    let expr: Expr = parse_quote!(a::b);
+    let path = Path::from_expr(&expr).unwrap();
+    assert_eq!(path.to_token_stream().to_string(), "a :: b");
+
+    let expr: Expr = parse_quote!("Vec<u8>");
+    let ty = Type::from_expr(&expr).unwrap();
+    assert_eq!(ty.to_token_stream().to_string(), "Vec < u8 >");
+}
+
+#[test]
+fn mismatches_are_rejected() {
+    let expr: Expr = parse_quote!(42);
+    let err = String::from_expr(&expr).err().unwrap();
+    assert_eq!(err.to_string(), "Expected String Literal");
+
+    let meta: Meta = parse_quote!(rename);
+    let err = String::from_meta(&meta).err().unwrap();
+    assert_eq!(err.to_string(), "Expected `key = value`");
+}
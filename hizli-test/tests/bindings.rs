@@ -0,0 +1,357 @@
+use hizli::{
+    AccessMode, EnumBinding, FieldType, SignatureBinding, StructBinding, UnionBinding, assert_impl,
+    mentions_self,
+    proc_macro2::{Delimiter, Span, TokenTree},
+    quote::{ToTokens, quote},
+    syn::{
+        Data, DeriveInput, FnArg, Ident, Lifetime, Signature, parse_quote, parse_str, visit::Visit,
+    },
+};
+
+fn fields(src: &str) -> hizli::syn::Fields {
+    match parse_str::<DeriveInput>(src).unwrap().data {
+        hizli::syn::Data::Struct(s) => s.fields,
+        _ => panic!("Expected Struct"),
+    }
+}
+
+#[test]
+fn synthetic_idents_keep_the_field_span() {
+    let binding = StructBinding::new(&fields("struct Foo(u8, String);"));
+    let spans: Vec<_> = binding
+        .iter()
+        .map(|fb| fb.ident().span().source_text())
+        .collect();
+    assert_eq!(spans, [Some("u8".into()), Some("String".into())]);
+}
+
+#[test]
+fn reserved_idents_keep_the_field_span() {
+    let reserved = [Ident::new("input", Span::call_site())];
+    let binding = StructBinding::new_reserved(&fields("struct Foo { input: u8 }"), &reserved);
+    let fb = &binding.field_bindings()[0];
+    assert_eq!(fb.ident(), "input_");
+    assert_eq!(fb.ident().span().source_text().as_deref(), Some("input"));
+}
+
+#[test]
+fn synthetic_idents_avoid_inputs_named_like_them() {
+    let sig: Signature = parse_quote!(fn f(binding_1: u8, (a, b): (u8, u8), binding_1_: u8));
+    let binding = SignatureBinding::new(&sig);
+    let idents: Vec<String> = binding
+        .inputs()
+        .iter()
+        .map(|fb| fb.ident().to_string())
+        .collect();
+    assert_eq!(idents, ["binding_1", "binding_1__", "binding_1_"]);
+
+    let reserved = [Ident::new("binding_0", Span::call_site())];
+    let binding = StructBinding::new_reserved(&fields("struct Foo(u8, u8);"), &reserved);
+    assert_eq!(binding.forward_args().to_string(), "binding_0_ , binding_1");
+}
+
+#[test]
+fn members_and_idents_interpolate_directly() {
+    let binding = StructBinding::new(&fields("struct Foo(u8, u8);"));
+    let members = binding.members();
+    let idents = binding.idents();
+    assert_eq!(idents.len(), 2);
+    assert_eq!(
+        quote!(#(let #idents = self.#members;)*).to_string(),
+        "let binding_0 = self . 0 ; let binding_1 = self . 1 ;"
+    );
+
+    let named = StructBinding::new(&fields("struct Foo { a: u8, b: u8 }"));
+    assert_eq!(named.idents().next_back().unwrap(), "b");
+    let members = named.members();
+    assert_eq!(quote!(#(#members),*).to_string(), "a , b");
+}
+
+#[test]
+fn ignore_patterns_keep_every_position() {
+    let binding = StructBinding::new(&fields("struct Foo(u8, u8, u8);"));
+    assert_eq!(
+        binding
+            .ignore_pattern(|fb| fb.ident() == "binding_1")
+            .to_string(),
+        "(_ , binding_1 , _)"
+    );
+
+    let named = StructBinding::new(&fields("struct Foo { a: u8, b: u8 }"));
+    assert_eq!(
+        named.ignore_pattern(|fb| fb.ident() == "a").to_string(),
+        "{ a , b : _ }"
+    );
+
+    let (kept, _) = binding.partition(|fb| fb.ident() == "binding_0");
+    assert_eq!(
+        kept.ignore_pattern(|_| true).to_string(),
+        "(binding_0 , _ , _)"
+    );
+}
+
+#[test]
+fn with_span_replaces_the_binding_span() {
+    let span = parse_str::<Ident>("elsewhere").unwrap().span();
+    let binding = StructBinding::new(&fields("struct Foo(u8);")).with_span(span);
+    let fb = &binding.field_bindings()[0];
+    assert_eq!(fb.ident(), "binding_0");
+    assert_eq!(
+        fb.ident().span().source_text().as_deref(),
+        Some("elsewhere")
+    );
+}
+
+#[test]
+fn bindings_compare_structurally() {
+    let named = StructBinding::new(&fields("struct Foo { a: u8 }"));
+    assert_eq!(named, StructBinding::new(&fields("struct Bar { a: u8 }")));
+    assert_ne!(named, StructBinding::new(&fields("struct Foo { a: u16 }")));
+    assert_eq!(named.field_type(), FieldType::Named);
+}
+
+#[test]
+fn bindings_are_debug() {
+    let binding = StructBinding::new(&fields("struct Foo(u8);"));
+    let debug = format!("{:?}", binding.field_bindings()[0]);
+    assert!(debug.contains("binding_0"), "{debug}");
+}
+
+#[test]
+fn partitions_bind_their_own_fields() {
+    let binding = StructBinding::new(&fields("struct Foo { a: u8, b: u8, c: u8 }"));
+    let (kept, rest) = binding.partition(|fb| fb.ident() != "b");
+    assert_eq!(kept.pattern().to_string(), "{ a , c , .. }");
+    assert_eq!(rest.pattern().to_string(), "{ b , .. }");
+    assert_eq!(
+        rest.reconstruct().to_string(),
+        "{ a : :: core :: default :: Default :: default () , b , \
+         c : :: core :: default :: Default :: default () }"
+    );
+}
+
+#[test]
+fn nested_partitions_omit_in_declaration_order() {
+    let binding = StructBinding::new(&fields("struct Foo { a: u8, b: u8, c: u8, d: u8 }"));
+    let (outer, _) = binding.partition(|fb| fb.ident() != "b");
+    let (inner, _) = outer.partition(|fb| fb.ident() != "c");
+    let omitted: Vec<String> = inner
+        .omitted()
+        .iter()
+        .map(|m| m.to_token_stream().to_string())
+        .collect();
+    assert_eq!(omitted, ["b", "c"]);
+}
+
+#[test]
+fn positional_partitions_keep_their_positions() {
+    let binding = StructBinding::new(&fields("struct Foo(u8, u8, u8);"));
+    let (kept, rest) = binding.partition(|fb| fb.ident() != "binding_1");
+    assert_eq!(kept.pattern().to_string(), "(binding_0 , _ , binding_2)");
+    assert_eq!(rest.pattern().to_string(), "(_ , binding_1 , ..)");
+    assert_eq!(
+        rest.reconstruct().to_string(),
+        "(:: core :: default :: Default :: default () , binding_1 , \
+         :: core :: default :: Default :: default ())"
+    );
+
+    let (none, all) = binding.partition(|_| false);
+    assert_eq!(none.pattern().to_string(), "(..)");
+    assert_eq!(all.pattern().to_string(), binding.pattern().to_string());
+}
+
+struct Lifetimes(Vec<String>);
+
+impl<'ast> Visit<'ast> for Lifetimes {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.0.push(lifetime.to_string());
+    }
+}
+
+#[test]
+fn visitors_run_over_field_types() {
+    let input: DeriveInput = parse_quote! {
+        enum Foo<'a, 'b> { A(&'a u8), B { b: Cow<'b, str>, c: u8 } }
+    };
+    let (binding, _, _) = EnumBinding::try_from_input(&input, "MyMacro").unwrap();
+    let mut lifetimes = Lifetimes(Vec::new());
+    binding.visit_types(&mut lifetimes);
+    assert_eq!(lifetimes.0, ["'a", "'b"]);
+}
+
+#[test]
+fn type_params_map_to_the_fields_mentioning_them() {
+    let input: DeriveInput = parse_str(
+        "struct Foo<'a, T, U, V: Iterator, const N: usize> {
+            a: T,
+            b: Vec<U>,
+            c: &'a [u8; N],
+            d: <V as Iterator>::Item,
+            e: Vec<T>,
+            f: T,
+        }",
+    )
+    .unwrap();
+    let binding = match &input.data {
+        Data::Struct(s) => StructBinding::new(&s.fields),
+        _ => panic!("Expected Struct"),
+    };
+    let usage = binding.type_param_usage(&input.generics);
+
+    let names = |param: &str| -> Vec<String> {
+        usage
+            .fields(&Ident::new(param, Span::call_site()))
+            .iter()
+            .map(|fb| fb.ident().to_string())
+            .collect()
+    };
+    assert_eq!(names("T"), ["a", "e", "f"]);
+    assert_eq!(names("U"), ["b"]);
+    assert_eq!(names("V"), ["d"]);
+    assert!(names("N").is_empty());
+    assert_eq!(usage.unused().count(), 0);
+
+    let bounds: Vec<String> = usage
+        .bounds(&parse_quote!(Clone))
+        .iter()
+        .map(|bound| bound.to_token_stream().to_string())
+        .collect();
+    assert_eq!(
+        bounds,
+        [
+            "T : Clone",
+            "Vec < T > : Clone",
+            "Vec < U > : Clone",
+            "< V as Iterator > :: Item : Clone"
+        ]
+    );
+}
+
+#[test]
+fn self_referential_fields_are_not_bounded() {
+    let input: DeriveInput = parse_str(
+        "enum Expr<T> {
+            Lit(T),
+            Neg(Box<Expr<T>>),
+            Args(Vec<Self>),
+            Path(Option<crate::ast::Expr<T>>),
+        }",
+    )
+    .unwrap();
+    let binding = match &input.data {
+        Data::Enum(e) => EnumBinding::new(e),
+        _ => panic!("Expected Enum"),
+    };
+    let recursive: Vec<bool> = binding
+        .iter()
+        .map(|vb| vb.field_bindings()[0].is_self_referential(&input.ident))
+        .collect();
+    assert_eq!(recursive, [false, true, true, true]);
+    assert!(!mentions_self(&parse_quote!(Vec<Exprs>), &input.ident));
+
+    let usage = binding
+        .type_param_usage(&input.generics)
+        .skip_self_referential(&input.ident);
+    let bounds: Vec<String> = usage
+        .bounds(&parse_quote!(Clone))
+        .iter()
+        .map(|bound| bound.to_token_stream().to_string())
+        .collect();
+    assert_eq!(bounds, ["T : Clone"]);
+}
+
+#[test]
+#[should_panic(expected = "Expected 2 Expressions, One Per Field, Found 1")]
+fn construct_rejects_missing_expressions() {
+    let binding = StructBinding::new(&fields("struct Foo { a: u8, b: u8 }"));
+    binding.construct([quote! { 1 }]);
+}
+
+#[test]
+fn try_construct_reports_missing_expressions() {
+    let binding = StructBinding::new(&fields("struct Foo { a: u8, b: u8 }"));
+    let err = binding.try_construct([quote! { 1 }]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected 2 Expressions, One Per Field, Found 1"
+    );
+    let init = binding.try_construct([quote! { 1 }, quote! { b }]).unwrap();
+    assert_eq!(init.to_string(), "{ a : 1 , b }");
+}
+
+#[test]
+fn impl_assertions_point_at_each_field_type() {
+    let input: DeriveInput = parse_str("struct Foo<T> { a: u8, b: T }").unwrap();
+    let (binding, _, generics) = StructBinding::try_from_input(&input, "MyMacro").unwrap();
+    let tokens = assert_impl(binding.field_bindings(), &parse_quote!(Clone), generics);
+    assert_eq!(
+        tokens.to_string(),
+        "const _ : () = { # [allow (dead_code)] fn assert_impl < T : ? :: core :: marker :: Sized \
+         + Clone > () { } # [allow (dead_code)] fn assert_fields < T > () { \
+         assert_impl :: < u8 > () ; assert_impl :: < T > () ; } } ;"
+    );
+
+    let spans: Vec<_> = tokens
+        .into_iter()
+        .flat_map(|tt| match tt {
+            TokenTree::Group(group) => group.stream().into_iter().collect(),
+            _ => Vec::new(),
+        })
+        .filter_map(|tt| match tt {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => Some(group),
+            _ => None,
+        })
+        .flat_map(|group| group.stream())
+        .filter(|tt| matches!(tt, TokenTree::Ident(id) if id == "assert_impl"))
+        .map(|tt| tt.span().source_text())
+        .collect();
+    assert_eq!(spans, [Some("u8".into()), Some("T".into())]);
+}
+
+#[test]
+fn fn_args_pair_with_forward_args() {
+    let named = StructBinding::new(&fields("struct Foo { a: u8, b: Vec<T> }"));
+    assert_eq!(named.fn_args().to_string(), "a : u8 , b : Vec < T >");
+    assert_eq!(named.forward_args().to_string(), "a , b");
+
+    let tuple = StructBinding::new(&fields("struct Foo(u8, String);"));
+    let args = tuple.fn_args();
+    let sig: Signature = parse_quote!(fn new(#args) -> Self);
+    let params: Vec<String> = sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            FnArg::Typed(pat_type) => pat_type.pat.to_token_stream().to_string(),
+            FnArg::Receiver(_) => panic!("Expected Typed Argument"),
+        })
+        .collect();
+    assert_eq!(params, ["binding_0", "binding_1"]);
+    assert_eq!(tuple.forward_args().to_string(), params.join(" , "));
+}
+
+#[test]
+fn unions_bind_their_named_fields() {
+    let input: DeriveInput = parse_str("union Bits { int: u32, float: f32 }").unwrap();
+    let binding = match &input.data {
+        Data::Union(data) => UnionBinding::new(data),
+        _ => panic!("Expected Union"),
+    };
+    let idents: Vec<String> = binding
+        .field_bindings()
+        .iter()
+        .map(|fb| fb.ident().to_string())
+        .collect();
+    assert_eq!(idents, ["int", "float"]);
+
+    let float = &binding.field_bindings()[1];
+    assert_eq!(
+        binding
+            .unsafe_access(float, &quote!(self), AccessMode::Ref)
+            .to_string(),
+        "unsafe { & self . float }"
+    );
+    assert_eq!(
+        binding.construct(float, quote!(1.0)).to_string(),
+        "{ float : 1.0 }"
+    );
+}
@@ -0,0 +1,44 @@
+use hizli::{
+    FieldType,
+    compat::{syn1, syn3},
+    quote::ToTokens,
+};
+
+const INPUT: &str = "enum Shape { Circle { radius: f32 }, Square(f32), Empty }";
+
+#[test]
+fn syn1_input_converts_to_syn2() {
+    let input: syn1::syn::DeriveInput = syn1::syn::parse_str(INPUT).unwrap();
+    let converted = syn1::derive_input(&input).unwrap();
+    assert_eq!(
+        converted.to_token_stream().to_string(),
+        input.to_token_stream().to_string()
+    );
+}
+
+#[test]
+fn syn1_enum_binding_matches_syn2() {
+    let input: syn1::syn::DeriveInput = syn1::syn::parse_str(INPUT).unwrap();
+    let syn1::syn::Data::Enum(data) = &input.data else {
+        panic!("Expected Enum");
+    };
+    let binding = syn1::enum_binding(data).unwrap();
+    let shapes: Vec<_> = binding
+        .variant_bindings()
+        .iter()
+        .map(|vb| vb.field_type())
+        .collect();
+    assert!(shapes == [FieldType::Named, FieldType::Unnamed, FieldType::Unit]);
+}
+
+#[test]
+fn syn3_struct_binding_keeps_field_names() {
+    let input: syn3::syn::DeriveInput =
+        syn3::syn::parse_str("struct Point { x: i32, y: i32 }").unwrap();
+    let syn3::syn::Data::Struct(data) = &input.data else {
+        panic!("Expected Struct");
+    };
+    let binding = syn3::struct_binding(&data.fields).unwrap();
+    let names: Vec<_> = binding.iter().map(|fb| fb.ident().to_string()).collect();
+    assert_eq!(names, ["x", "y"]);
+}
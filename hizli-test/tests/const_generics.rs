@@ -0,0 +1,55 @@
+use hizli::{
+    bound_type_params, impl_block,
+    quote::ToTokens,
+    syn::{DeriveInput, Ident, TypeParamBound, parse_quote},
+    turbofish, type_path,
+};
+
+fn input() -> DeriveInput {
+    parse_quote! {
+        struct Foo<'a, T: Clone, const N: usize = 3> where T: Default {
+            slice: &'a [T; N],
+        }
+    }
+}
+
+#[test]
+fn type_path_carries_const_params() {
+    let input = input();
+    assert_eq!(
+        type_path(&input.ident, &input.generics).to_string(),
+        "Foo < 'a , T , N >",
+    );
+    assert_eq!(
+        turbofish(&input.ident, &input.generics).to_string(),
+        "Foo :: < 'a , T , N >",
+    );
+}
+
+#[test]
+fn bound_type_params_skips_const_params() {
+    let input = input();
+    let bound: TypeParamBound = parse_quote!(::core::fmt::Debug);
+    let generics = bound_type_params(&input.generics, &bound);
+    let (impl_gen, _, _) = generics.split_for_impl();
+    assert_eq!(
+        impl_gen.to_token_stream().to_string(),
+        "< 'a , T : Clone + :: core :: fmt :: Debug , const N : usize >",
+    );
+}
+
+#[test]
+fn impl_block_declares_const_params() {
+    let input = input();
+    let trait_path: Ident = parse_quote!(Marker);
+    let tokens = impl_block(
+        &input.ident,
+        &input.generics,
+        Some(trait_path),
+        Default::default(),
+    );
+    assert_eq!(
+        tokens.to_string(),
+        "# [automatically_derived] impl < 'a , T : Clone , const N : usize > Marker for Foo < 'a , T , N > where T : Default { }",
+    );
+}
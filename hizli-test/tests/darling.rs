@@ -0,0 +1,52 @@
+use hizli::{
+    EnumBinding, FieldBinding, FieldType, StructBinding, VariantBinding,
+    darling::ast::{Data, Fields, Style},
+    syn::{DeriveInput, Field, FieldsNamed, Variant, parse_quote},
+};
+
+fn data(input: DeriveInput) -> Data<Variant, Field> {
+    Data::try_from(&input.data).unwrap()
+}
+
+#[test]
+fn darling_structs_convert_to_bindings() {
+    let data = data(parse_quote! { struct Foo(u8, u16); });
+    let binding = StructBinding::try_from_darling(data, "MyMacro").unwrap();
+    assert_eq!(binding.field_type(), FieldType::Unnamed);
+    let idents: Vec<_> = binding.iter().map(|fb| fb.ident().to_string()).collect();
+    assert_eq!(idents, ["binding_0", "binding_1"]);
+}
+
+#[test]
+fn darling_enums_convert_to_bindings() {
+    let data = data(parse_quote! { enum Foo { A { a: u8 }, B } });
+    let binding = EnumBinding::try_from_darling(data.clone(), "MyMacro").unwrap();
+    let variants: Vec<_> = binding.iter().map(|vb| vb.ident().to_string()).collect();
+    assert_eq!(variants, ["A", "B"]);
+
+    let err = StructBinding::try_from_darling(data, "MyMacro")
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "Cannot #[derive(MyMacro)] On Enum");
+}
+
+#[test]
+fn darling_structs_are_rejected_as_enums() {
+    let data = data(parse_quote! { struct Foo; });
+    let err = EnumBinding::try_from_darling(data, "MyMacro")
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "Cannot #[derive(MyMacro)] On Struct");
+}
+
+#[test]
+fn bindings_convert_to_darling() {
+    let fields: FieldsNamed = parse_quote!({ a: u8, b: u8 });
+    let binding = StructBinding::new(&hizli::syn::Fields::Named(fields));
+    let fields: Fields<FieldBinding> = binding.clone().into();
+    assert_eq!(fields.style, Style::Struct);
+    assert_eq!(fields.fields, binding.field_bindings());
+
+    let data: Data<VariantBinding, FieldBinding> = binding.into();
+    assert!(data.is_struct());
+}
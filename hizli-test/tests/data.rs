@@ -0,0 +1,87 @@
+use hizli::{
+    SingleVariantEnumOnly, require_field_count, require_field_count_at_least,
+    require_field_count_at_most,
+    syn::{Data, DeriveInput, parse_str},
+};
+use hizli_test::ui::assert_error;
+
+fn input(source: &str) -> DeriveInput {
+    parse_str(source).unwrap()
+}
+
+#[test]
+fn single_variant_enums_are_accepted() {
+    let single =
+        SingleVariantEnumOnly::try_new(input("enum Id { Raw(u32) }").data, "Wrapper").unwrap();
+    assert_eq!(single.variant().ident, "Raw");
+}
+
+#[test]
+fn other_shapes_name_the_derive() {
+    assert_error(
+        SingleVariantEnumOnly::try_new(input("struct Id(u32);").data, "Wrapper"),
+        "Cannot #[derive(Wrapper)] On Struct",
+        "struct",
+    );
+    assert_error(
+        SingleVariantEnumOnly::try_new(input("enum Id {}").data, "Wrapper"),
+        "Cannot #[derive(Wrapper)] On An Enum Without Variants",
+        "enum",
+    );
+    assert_error(
+        SingleVariantEnumOnly::try_new(
+            input("enum Id { Raw(u32), Named(String) }").data,
+            "Wrapper",
+        ),
+        "#[derive(Wrapper)] Requires An Enum With Exactly One Variant",
+        "Named",
+    );
+}
+
+#[test]
+fn field_counts_point_at_the_extra_field() {
+    let data = match input("struct Pair(u8, u16, u32);").data {
+        Data::Struct(data) => data,
+        _ => unreachable!(),
+    };
+    assert!(require_field_count_at_least(&data.fields, 2, "Pair").is_ok());
+    assert_error(
+        require_field_count(&data.fields, 2, "Pair"),
+        "#[derive(Pair)] Requires Exactly 2 Fields",
+        "u32",
+    );
+    assert_error(
+        require_field_count_at_most(&data.fields, 1, "Newtype"),
+        "#[derive(Newtype)] Requires At Most 1 Field",
+        "u16",
+    );
+}
+
+#[test]
+fn field_counts_point_at_the_missing_position() {
+    let data = match input("struct Named { a: u8 }").data {
+        Data::Struct(data) => data,
+        _ => unreachable!(),
+    };
+    assert!(require_field_count_at_most(&data.fields, 1, "Newtype").is_ok());
+    assert_error(
+        require_field_count_at_least(&data.fields, 2, "Pair"),
+        "#[derive(Pair)] Requires At Least 2 Fields",
+        "}",
+    );
+}
+
+#[test]
+fn field_counts_of_unit_structs_point_at_the_call_site() {
+    let data = match input("struct Unit;").data {
+        Data::Struct(data) => data,
+        _ => unreachable!(),
+    };
+    assert!(require_field_count(&data.fields, 0, "Marker").is_ok());
+    let err = require_field_count_at_least(&data.fields, 1, "Newtype").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "#[derive(Newtype)] Requires At Least 1 Field"
+    );
+    assert!(err.span().source_text().is_none());
+}
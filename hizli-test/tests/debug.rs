@@ -0,0 +1,21 @@
+use std::{env, fs};
+
+use hizli::{dump_expansion, quote::quote};
+
+#[test]
+fn expansions_are_appended_pretty_printed() {
+    let path = env::temp_dir().join(format!("hizli-expand-{}.rs", std::process::id()));
+    let _ = fs::remove_file(&path);
+    // SAFETY: this is the only test in the binary, so no other thread reads the environment.
+    unsafe { env::set_var("HIZLI_EXPAND_DEBUG", &path) };
+
+    dump_expansion("first", &quote! { impl Marker for Foo {} });
+    dump_expansion("second", &quote! { 1 + 2 });
+
+    let dump = fs::read_to_string(&path).unwrap();
+    let _ = fs::remove_file(&path);
+    assert_eq!(
+        dump,
+        "// ---- first ----\nimpl Marker for Foo {}\n\n// ---- second ----\n1 + 2\n"
+    );
+}
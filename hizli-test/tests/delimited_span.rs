@@ -0,0 +1,46 @@
+use hizli::{
+    DelimitedSpan, Parse, assert_parses,
+    proc_macro2::{Delimiter, Group, TokenStream, TokenTree},
+    syn::{Ident, parse_str, token},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli, delimited = bracket)]
+struct List {
+    delimited: DelimitedSpan,
+    item: Ident,
+}
+
+fn group(source: &str) -> Group {
+    match parse_str::<TokenStream>(source).unwrap().into_iter().next() {
+        Some(TokenTree::Group(group)) => group,
+        _ => panic!("Expected A Group"),
+    }
+}
+
+#[test]
+fn delimited_fields_receive_the_spans() {
+    let list = assert_parses!(List, { [a] });
+    assert_eq!(list.item, "a");
+    assert_eq!(list.delimited.delimiter(), Delimiter::Bracket);
+    assert_eq!(list.delimited.open().source_text().as_deref(), Some("["));
+    assert_eq!(list.delimited.close().source_text().as_deref(), Some("]"));
+    assert_eq!(list.delimited.span().source_text().as_deref(), Some("[a]"));
+}
+
+#[test]
+fn groups_and_tokens_convert() {
+    let delimited = DelimitedSpan::from(&group("( x )"));
+    assert_eq!(delimited.delimiter(), Delimiter::Parenthesis);
+    assert_eq!(delimited.close().source_text().as_deref(), Some(")"));
+
+    let brace = DelimitedSpan::from(token::Brace::default());
+    assert_eq!(brace.delimiter(), Delimiter::Brace);
+}
+
+#[test]
+fn unclosed_errors_point_at_the_open_delimiter() {
+    let err = DelimitedSpan::from(&group("{ x }")).error_unclosed();
+    assert_eq!(err.to_string(), "Unclosed Delimiter `{`");
+    assert_eq!(err.span().source_text().as_deref(), Some("{"));
+}
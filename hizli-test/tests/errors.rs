@@ -0,0 +1,53 @@
+use hizli::{
+    ErrorExt, collect_all,
+    proc_macro2::Span,
+    syn::{Error, Result},
+};
+use hizli_test::ui::messages;
+
+#[test]
+fn with_context_puts_the_note_first() {
+    let result: Result<()> = Err(Error::new(Span::call_site(), "expected `=`"));
+    let err = result
+        .with_context(
+            Span::call_site(),
+            "While Expanding #[derive(Parse)] For `Foo`",
+        )
+        .unwrap_err();
+    assert_eq!(
+        messages(&err),
+        ["While Expanding #[derive(Parse)] For `Foo`", "expected `=`"],
+    );
+}
+
+#[test]
+fn with_context_keeps_every_inner_message() {
+    let mut inner = Error::new(Span::call_site(), "first");
+    inner.combine(Error::new(Span::call_site(), "second"));
+    let err = Err::<(), _>(inner)
+        .with_context(Span::call_site(), "outer")
+        .unwrap_err();
+    assert_eq!(messages(&err), ["outer", "first", "second"]);
+}
+
+#[test]
+fn with_context_passes_values_through() {
+    let value = Ok::<_, Error>(3).with_context(Span::call_site(), "unused");
+    assert_eq!(value.unwrap(), 3);
+}
+
+#[test]
+fn collect_all_keeps_every_value() {
+    let values = collect_all([Ok(1), Ok(2), Ok(3)]).unwrap();
+    assert_eq!(values, [1, 2, 3]);
+}
+
+#[test]
+fn collect_all_combines_every_error_in_order() {
+    let items = ["a", "1", "b", "2"].map(|item| match item.parse::<u8>() {
+        Ok(value) => Ok(value),
+        Err(_) => Err(Error::new(Span::call_site(), format!("Invalid `{item}`"))),
+    });
+    let err = collect_all(items).unwrap_err();
+    assert_eq!(messages(&err), ["Invalid `a`", "Invalid `b`"]);
+}
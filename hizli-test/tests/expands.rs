@@ -0,0 +1,73 @@
+use hizli::{
+    assert_expands,
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{DeriveInput, Error, Result},
+};
+
+fn handler(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    if ident == "Bad" {
+        return Err(Error::new(ident.span(), "Bad Input"));
+    }
+    Ok(quote! {
+        impl Marker for #ident {}
+    })
+}
+
+#[test]
+fn expansion_matches_inline_snapshot() {
+    assert_expands!(
+        handler,
+        {
+            struct A;
+        },
+        "impl Marker for A {}"
+    );
+    assert_expands!(
+        handler,
+        {
+            struct Bad;
+        },
+        r#"
+            ::core::compile_error! {
+                "Bad Input"
+            }
+        "#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Expansion Does Not Match The Snapshot")]
+fn mismatched_snapshot_panics() {
+    assert_expands!(
+        handler,
+        {
+            struct A;
+        },
+        "impl Marker for B {}"
+    );
+}
+
+#[test]
+fn expansion_matches_file_snapshot() {
+    assert_expands!(
+        handler,
+        {
+            struct A;
+        },
+        file = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/marker.rs")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Missing Snapshot")]
+fn missing_file_snapshot_fails() {
+    assert_expands!(
+        handler,
+        {
+            struct A;
+        },
+        file = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/missing.rs")
+    );
+}
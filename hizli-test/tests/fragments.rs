@@ -0,0 +1,26 @@
+use hizli::{Fragments, quote::quote};
+
+#[test]
+fn fragments_print_their_paths() {
+    let fragments = Fragments::new();
+    assert_eq!(
+        fragments.ok().to_string(),
+        ":: core :: result :: Result :: Ok"
+    );
+    assert_eq!(
+        fragments.default_expr().to_string(),
+        ":: core :: default :: Default :: default ()"
+    );
+}
+
+#[test]
+fn vec_follows_the_alloc_then_the_root() {
+    assert_eq!(Fragments::new().vec().to_string(), ":: std :: vec :: Vec");
+
+    let rooted = Fragments::with_root(quote! { core });
+    assert_eq!(rooted.vec().to_string(), "core :: vec :: Vec");
+
+    let alloc = Fragments::with_root(quote! { core }).with_alloc(quote! { alloc });
+    assert_eq!(alloc.vec().to_string(), "alloc :: vec :: Vec");
+    assert_eq!(alloc.ok().to_string(), "core :: result :: Result :: Ok");
+}
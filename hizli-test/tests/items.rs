@@ -0,0 +1,146 @@
+use hizli::{
+    ImplBlockBinding, SignatureBinding, TraitBinding,
+    quote::{ToTokens, quote},
+    syn::{ItemFn, ItemImpl, ItemTrait, Signature, parse_quote},
+};
+
+#[test]
+fn signatures_expose_receiver_inputs_and_output() {
+    let sig: Signature = parse_quote!(async fn get(&mut self, a: u8, (b, c): (u8, u8)) -> u16);
+    let binding = SignatureBinding::new(&sig);
+    assert_eq!(binding.ident(), "get");
+    assert!(binding.is_async());
+    assert_eq!(
+        binding.receiver().unwrap().to_token_stream().to_string(),
+        "& mut self"
+    );
+    assert_eq!(binding.inputs().len(), 2);
+    assert_eq!(
+        binding.output_ty().unwrap().to_token_stream().to_string(),
+        "u16"
+    );
+    assert_eq!(
+        binding.fn_args().to_string(),
+        "a : u8 , binding_1 : (u8 , u8)"
+    );
+
+    let free = SignatureBinding::new(&parse_quote!(fn free()));
+    assert!(free.receiver().is_none());
+    assert!(free.output_ty().is_none());
+    assert!(!free.is_async());
+}
+
+#[test]
+fn bound_signatures_forward_every_input() {
+    let item: ItemFn = parse_quote! {
+        async fn get<T: Default>(&self, a: u8, (b, c): (u8, u8)) -> T { T::default() }
+    };
+    let binding = SignatureBinding::from_item_fn(&item);
+    let bound = binding.bound_signature();
+    let call = binding.forward_call(&quote!(self.inner));
+    assert_eq!(call.to_string(), "self . inner (a , binding_1) . await");
+
+    let wrapper: ItemFn = parse_quote!(#bound { #call });
+    assert_eq!(
+        wrapper.sig.to_token_stream().to_string(),
+        "async fn get < T : Default > (& self , a : u8 , binding_1 : (u8 , u8)) -> T"
+    );
+}
+
+#[test]
+fn impl_blocks_expose_their_parts() {
+    let item: ItemImpl = parse_quote! {
+        impl<T: Clone> Trait for Foo<T> {
+            type Out = T;
+            fn get(&self) -> T { self.0.clone() }
+            fn set(&mut self, value: T) { self.0 = value; }
+        }
+    };
+    let binding = ImplBlockBinding::new(&item);
+    assert_eq!(binding.self_ty().to_token_stream().to_string(), "Foo < T >");
+    assert_eq!(
+        binding.trait_path().unwrap().to_token_stream().to_string(),
+        "Trait"
+    );
+    assert_eq!(binding.generics().params.len(), 1);
+    let methods: Vec<String> = binding
+        .methods()
+        .iter()
+        .map(|method| method.ident().to_string())
+        .collect();
+    assert_eq!(methods, ["get", "set"]);
+
+    let inherent: ItemImpl = parse_quote!(impl Foo {});
+    assert!(ImplBlockBinding::new(&inherent).trait_path().is_none());
+}
+
+#[test]
+fn impl_blocks_regenerate_with_injected_items() {
+    let item: ItemImpl = parse_quote! {
+        impl<T> Foo<T> where T: Clone {
+            fn get(&self) -> T { self.0.clone() }
+        }
+    };
+    let binding = ImplBlockBinding::new(&item);
+    let regenerated = binding.with_items(quote! { fn reset(&mut self) {} });
+    let regenerated: ItemImpl = parse_quote!(#regenerated);
+    assert_eq!(regenerated.generics.params.len(), 1);
+    assert!(regenerated.generics.where_clause.is_some());
+    let names: Vec<String> = ImplBlockBinding::new(&regenerated)
+        .methods()
+        .iter()
+        .map(|method| method.ident().to_string())
+        .collect();
+    assert_eq!(names, ["get", "reset"]);
+}
+
+#[test]
+fn traits_expose_methods_and_associated_items() {
+    let item: ItemTrait = parse_quote! {
+        trait Store {
+            type Key;
+            const LEN: usize;
+            fn get(&self, key: Self::Key) -> u8;
+            fn clear(&mut self) {}
+        }
+    };
+    let binding = TraitBinding::new(&item);
+    assert_eq!(binding.ident(), "Store");
+    assert_eq!(binding.assoc_types().len(), 1);
+    assert_eq!(binding.assoc_consts()[0].ident, "LEN");
+    let methods: Vec<(String, bool)> = binding
+        .methods()
+        .iter()
+        .map(|method| (method.signature().ident().to_string(), method.has_default()))
+        .collect();
+    assert_eq!(
+        methods,
+        [("get".to_string(), false), ("clear".to_string(), true)]
+    );
+}
+
+#[test]
+fn delegating_impls_forward_every_item() {
+    let item: ItemTrait = parse_quote! {
+        trait Store {
+            type Key;
+            fn get(&self, key: Self::Key) -> u8;
+            fn clear(&mut self);
+            async fn into_len(self) -> usize;
+        }
+    };
+    let delegate = TraitBinding::new(&item).delegate_impl(
+        &parse_quote!(Wrapper),
+        &parse_quote!(Inner),
+        &quote!(self.0),
+    );
+    let delegate: ItemImpl = parse_quote!(#delegate);
+    assert_eq!(
+        delegate.to_token_stream().to_string(),
+        "impl Store for Wrapper { \
+         type Key = < Inner as Store > :: Key ; \
+         fn get (& self , key : Self :: Key) -> u8 { < Inner as Store > :: get (& self . 0 , key) } \
+         fn clear (& mut self) { < Inner as Store > :: clear (& mut self . 0) } \
+         async fn into_len (self) -> usize { < Inner as Store > :: into_len (self . 0) . await } }"
+    );
+}
@@ -0,0 +1,30 @@
+use hizli::{
+    syn::{Expr, Ident, parse_quote},
+    value_error,
+};
+
+#[test]
+fn value_errors_describe_the_found_value() {
+    let key: Ident = parse_quote!(rename);
+
+    let value: Expr = parse_quote!("a");
+    let err = value_error(&key, &value, "Identifier");
+    assert_eq!(
+        err.to_string(),
+        "Key `rename` Expected Identifier, Found String Literal"
+    );
+
+    let value: Expr = parse_quote!(a::b);
+    let err = value_error(&key, &value, "Identifier");
+    assert_eq!(
+        err.to_string(),
+        "Key `rename` Expected Identifier, Found Path"
+    );
+
+    let value: Expr = parse_quote!(b'a');
+    let err = value_error(&key, &value, "Identifier");
+    assert_eq!(
+        err.to_string(),
+        "Key `rename` Expected Identifier, Found Byte Literal"
+    );
+}
@@ -0,0 +1,519 @@
+use hizli::{
+    AttrLevel, KeySet, LevelRule, NsAttr, StructBinding, check_level_rules,
+    proc_macro2::TokenStream,
+    quote::{ToTokens, quote},
+    syn::{Attribute, Ident, LitInt, LitStr, Meta, Path, parse_quote},
+};
+
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct MyAttr {
+    skip: bool,
+    rename: Option<LitStr>,
+    #[hizli(default = parse_quote!(::core::default::Default))]
+    with: Path,
+    #[hizli(skip)]
+    ignored: u8,
+}
+
+fn parse(attrs: Vec<Attribute>) -> hizli::syn::Result<Option<MyAttr>> {
+    MyAttr::from_attrs_opt(&attrs)
+}
+
+#[test]
+fn missing_keys_use_defaults() {
+    let attr = parse(vec![parse_quote!(#[myattr()])]).unwrap().unwrap();
+    assert!(!attr.skip);
+    assert!(attr.rename.is_none());
+    assert_eq!(
+        attr.with.to_token_stream().to_string(),
+        ":: core :: default :: Default",
+    );
+    assert_eq!(attr.ignored, 0);
+}
+
+#[test]
+fn bare_attribute_uses_defaults() {
+    let attr = parse(vec![parse_quote!(#[myattr])]).unwrap().unwrap();
+    assert!(!attr.skip);
+}
+
+#[test]
+fn keys_are_parsed() {
+    let attr = parse(vec![
+        parse_quote!(#[myattr(skip, rename = "b", with = my::path)]),
+    ])
+    .unwrap()
+    .unwrap();
+    assert!(attr.skip);
+    assert_eq!(attr.rename.unwrap().value(), "b");
+    assert_eq!(attr.with.to_token_stream().to_string(), "my :: path");
+}
+
+#[test]
+fn unknown_and_duplicate_keys_are_rejected() {
+    let err = parse(vec![parse_quote!(#[myattr(skp)])]).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Unknown Key `skp`, Did You Mean `skip`? (Accepted Keys: `skip`, `rename`, `with`)"
+    );
+
+    let err = parse(vec![parse_quote!(#[myattr(skip, skip)])])
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "Duplicate Key `skip`");
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "defaults")]
+struct Defaults {
+    #[hizli(default = true)]
+    flag: bool,
+    #[hizli(default = Some(parse_quote!(3)))]
+    count: Option<LitInt>,
+    #[hizli(default)]
+    name: Option<LitStr>,
+}
+
+#[test]
+fn defaults_apply_to_every_shape() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[defaults])];
+    let attr = Defaults::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert!(attr.flag);
+    assert_eq!(attr.count.unwrap().base10_digits(), "3");
+    assert!(attr.name.is_none());
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[defaults(count = 4, name = "a")])];
+    let attr = Defaults::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert_eq!(attr.count.unwrap().base10_digits(), "4");
+    assert_eq!(attr.name.unwrap().value(), "a");
+}
+
+#[test]
+fn flags_accept_an_explicit_bool() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[defaults(flag = false)])];
+    assert!(!Defaults::from_attrs_opt(&attrs).unwrap().unwrap().flag);
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[defaults(flag = true)])];
+    assert!(Defaults::from_attrs_opt(&attrs).unwrap().unwrap().flag);
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "strict")]
+struct Strict {
+    #[hizli(required)]
+    name: LitStr,
+    #[hizli(required)]
+    with: Path,
+    #[hizli(required)]
+    rename: Option<LitStr>,
+}
+
+#[test]
+fn required_keys_are_parsed() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[strict(name = "a", with = b, rename = "c")])];
+    let attr = Strict::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert_eq!(attr.name.value(), "a");
+    assert_eq!(attr.with.to_token_stream().to_string(), "b");
+    assert_eq!(attr.rename.unwrap().value(), "c");
+}
+
+#[test]
+fn missing_required_keys_are_aggregated() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[strict(with = b)])];
+    let err = Strict::from_attrs_opt(&attrs).err().unwrap();
+    assert_eq!(err.to_string(), "Missing Required Keys `name`, `rename`");
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[strict])];
+    let err = Strict::from_attrs_opt(&attrs).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Missing Required Keys `name`, `with`, `rename`"
+    );
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "sub")]
+struct Sub {
+    x: Option<LitInt>,
+    y: bool,
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "outer")]
+struct Outer {
+    #[hizli(nested)]
+    sub: Option<Sub>,
+    #[hizli(nested, required)]
+    other: Sub,
+}
+
+#[test]
+fn nested_groups_are_parsed() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[outer(sub(x = 1, y), other)])];
+    let attr = Outer::from_attrs_opt(&attrs).unwrap().unwrap();
+    let sub = attr.sub.unwrap();
+    assert_eq!(sub.x.unwrap().base10_parse::<u8>().unwrap(), 1);
+    assert!(sub.y);
+    assert!(attr.other.x.is_none());
+    assert!(!attr.other.y);
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[outer(other(z))])];
+    let err = Outer::from_attrs_opt(&attrs).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Unknown Key `z`, Did You Mean `x`? (Accepted Keys: `x`, `y`)"
+    );
+}
+
+#[derive(Clone, NsAttr)]
+#[hizli(ns = "conf", inherit)]
+struct Cfg {
+    skip: bool,
+    rename: Option<LitStr>,
+    with: Option<Path>,
+}
+
+#[test]
+fn field_attributes_inherit_from_the_container() {
+    let input: hizli::syn::ItemStruct = parse_quote! {
+        #[conf(rename = "outer", with = a::b)]
+        struct S {
+            #[conf(rename = "inner", skip)]
+            x: u8,
+            y: u8,
+        }
+    };
+    let container = Cfg::from_attrs_opt(&input.attrs).unwrap();
+    let binding = StructBinding::new(&input.fields);
+    let resolved = Cfg::resolve_fields(container.as_ref(), binding.field_bindings()).unwrap();
+
+    let (field, x) = &resolved[0];
+    let x = x.as_ref().unwrap();
+    assert_eq!(field.ident(), "x");
+    assert!(x.skip);
+    assert_eq!(x.rename.as_ref().unwrap().value(), "inner");
+    assert_eq!(x.with.to_token_stream().to_string(), "a :: b");
+
+    let y = resolved[1].1.as_ref().unwrap();
+    assert!(!y.skip);
+    assert_eq!(y.rename.as_ref().unwrap().value(), "outer");
+}
+
+#[test]
+fn explicit_field_flags_override_the_container() {
+    let input: hizli::syn::ItemStruct = parse_quote! {
+        #[conf(skip)]
+        struct S {
+            #[conf(skip = false)]
+            x: u8,
+            #[conf(rename = "y")]
+            y: u8,
+        }
+    };
+    let container = Cfg::from_attrs_opt(&input.attrs).unwrap();
+    let binding = StructBinding::new(&input.fields);
+    let resolved = Cfg::resolve_fields(container.as_ref(), binding.field_bindings()).unwrap();
+    assert!(!resolved[0].1.as_ref().unwrap().skip);
+    assert!(resolved[1].1.as_ref().unwrap().skip);
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "loose", lenient)]
+struct Loose {
+    skip: bool,
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "loose")]
+struct Collecting {
+    skip: bool,
+    #[hizli(unknown)]
+    unknown: Vec<Ident>,
+}
+
+#[test]
+fn lenient_mode_skips_unknown_keys() {
+    let attrs: Vec<Attribute> =
+        vec![parse_quote!(#[loose(future = Vec<u8>, skip, other(a, b), flag)])];
+
+    let attr = Loose::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert!(attr.skip);
+
+    let attr = Collecting::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert!(attr.skip);
+    let unknown = attr
+        .unknown
+        .iter()
+        .map(Ident::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(unknown, ["future", "other", "flag"]);
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "renamed", alias = ["legacy", "old::name"])]
+struct Renamed {
+    skip: bool,
+}
+
+#[test]
+fn alias_namespaces_are_accepted() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[old::name(skip)])];
+    assert!(Renamed::from_attrs_opt(&attrs).unwrap().unwrap().skip);
+
+    let attr: Attribute = parse_quote!(#[legacy]);
+    assert_eq!(Renamed::matched_ns(attr.path()), Some("legacy"));
+    let attr: Attribute = parse_quote!(#[renamed]);
+    assert_eq!(Renamed::matched_ns(attr.path()), Some("renamed"));
+    let attr: Attribute = parse_quote!(#[other]);
+    assert_eq!(Renamed::matched_ns(attr.path()), None);
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "evolving")]
+struct Evolving {
+    #[hizli(deprecated = ["rename_all", "case_style"])]
+    case: Option<LitStr>,
+    #[hizli(warnings)]
+    warnings: TokenStream,
+}
+
+#[test]
+fn deprecated_keys_parse_with_warnings() {
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[evolving(rename_all = "snake")])];
+    let attr = Evolving::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert_eq!(attr.case.unwrap().value(), "snake");
+    assert!(
+        attr.warnings
+            .to_string()
+            .contains("Key `rename_all` Is Deprecated, Use `case` Instead")
+    );
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[evolving(case = "a")])];
+    let attr = Evolving::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert!(attr.warnings.is_empty());
+
+    let attrs: Vec<Attribute> = vec![parse_quote!(#[evolving(case = "a", case_style = "b")])];
+    let err = Evolving::from_attrs_opt(&attrs).err().unwrap();
+    assert_eq!(err.to_string(), "Duplicate Key `case`");
+}
+
+#[test]
+fn strip_removes_parsed_attributes() {
+    let mut attrs: Vec<Attribute> = vec![
+        parse_quote!(#[doc = "a"]),
+        parse_quote!(#[myattr(skip)]),
+        parse_quote!(#[inline]),
+    ];
+    let attr = MyAttr::strip(&mut attrs).unwrap().unwrap();
+    assert!(attr.skip);
+    let rest = attrs
+        .iter()
+        .map(|attr| attr.to_token_stream().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(rest, ["# [doc = \"a\"]", "# [inline]"]);
+
+    let mut attrs: Vec<Attribute> = vec![parse_quote!(#[myattr(skp)])];
+    assert!(MyAttr::strip(&mut attrs).is_err());
+    assert_eq!(attrs.len(), 1);
+}
+
+#[test]
+fn macro_args_are_parsed() {
+    let attr = MyAttr::from_macro_args(quote!(skip, rename = "b")).unwrap();
+    assert!(attr.skip);
+    assert_eq!(attr.rename.unwrap().value(), "b");
+
+    let attr = MyAttr::from_macro_args(TokenStream::new()).unwrap();
+    assert!(!attr.skip);
+}
+
+#[test]
+fn others_keeps_foreign_attributes() {
+    let attrs: Vec<Attribute> = vec![
+        parse_quote!(#[derive(Clone)]),
+        parse_quote!(#[renamed(skip)]),
+        parse_quote!(#[legacy]),
+        parse_quote!(#[doc = "a"]),
+    ];
+    let names = |attrs: Vec<&Attribute>| {
+        attrs
+            .iter()
+            .map(|attr| attr.path().to_token_stream().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(hizli::others(&attrs, Renamed::NS).collect()),
+        ["derive", "legacy", "doc"]
+    );
+    assert_eq!(names(Renamed::others(&attrs).collect()), ["derive", "doc"]);
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "lvl")]
+#[allow(dead_code)]
+struct Leveled {
+    rename: Option<LitStr>,
+    skip: bool,
+    partial: bool,
+    #[hizli(keys)]
+    keys: KeySet,
+}
+
+const RULES: &[LevelRule] = &[
+    LevelRule::NotBoth {
+        key: "rename",
+        levels: [AttrLevel::Type, AttrLevel::Field],
+    },
+    LevelRule::Requires {
+        key: "skip",
+        level: AttrLevel::Field,
+        requires: "partial",
+        at: AttrLevel::Type,
+    },
+];
+
+fn check(container: Attribute, fields: Vec<Attribute>) -> hizli::syn::Result<()> {
+    let container = Leveled::from_attrs_opt(&[container]).unwrap().unwrap();
+    let fields = fields
+        .into_iter()
+        .map(|attr| Leveled::from_attrs_opt(&[attr]).unwrap().unwrap())
+        .collect::<Vec<_>>();
+    let mut present = vec![(AttrLevel::Type, &container.keys)];
+    present.extend(fields.iter().map(|field| (AttrLevel::Field, &field.keys)));
+    check_level_rules(RULES, &present)
+}
+
+#[test]
+fn level_rules_are_checked() {
+    check(
+        parse_quote!(#[lvl(rename = "a", partial)]),
+        vec![parse_quote!(#[lvl(skip)])],
+    )
+    .unwrap();
+
+    let err = check(
+        parse_quote!(#[lvl(rename = "a")]),
+        vec![
+            parse_quote!(#[lvl(rename = "b")]),
+            parse_quote!(#[lvl(skip)]),
+        ],
+    )
+    .err()
+    .unwrap();
+    let messages = err
+        .into_iter()
+        .map(|err| err.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "Key `rename` May Not Appear At Both The Type And Field Level",
+            "Key `skip` At The Field Level Requires `partial` At The Type Level",
+        ]
+    );
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "wrap")]
+struct Wrapper {
+    skip: bool,
+    #[hizli(passthrough)]
+    forwarded: Vec<Meta>,
+}
+
+#[test]
+fn passthrough_preserves_unknown_keys() {
+    let attrs: Vec<Attribute> =
+        vec![parse_quote!(#[wrap(rename = "a", skip, with = Vec<u8>, tag(x, y), flatten)])];
+    let attr = Wrapper::from_attrs_opt(&attrs).unwrap().unwrap();
+    assert!(attr.skip);
+    let forwarded = attr
+        .forwarded
+        .iter()
+        .map(|meta| meta.to_token_stream().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        forwarded,
+        [
+            "rename = \"a\"",
+            "with = Vec < u8 >",
+            "tag (x , y)",
+            "flatten"
+        ]
+    );
+}
+
+#[test]
+fn fields_are_paired_with_their_attributes() {
+    let input: hizli::syn::ItemStruct = parse_quote! {
+        struct S(#[myattr(skip)] u8, u16);
+    };
+    let fields = MyAttr::for_each_field(&input.fields).unwrap();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].0.ident(), "binding_0");
+    assert!(fields[0].1.as_ref().unwrap().skip);
+    assert_eq!(fields[1].0.ty().to_token_stream().to_string(), "u16");
+    assert!(fields[1].1.is_none());
+}
+
+#[test]
+fn missing_attribute_lists_accepted_keys() {
+    let span = hizli::proc_macro2::Span::call_site();
+    let err = MyAttr::from_attrs(&[], span).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Attribute #[myattr] Is Required (Accepted Keys: `skip`, `rename`, `with`)"
+    );
+
+    let err = Renamed::from_attrs(&[], span).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Attribute #[renamed] Or #[legacy] Or #[old::name] Is Required (Accepted Keys: `skip`)"
+    );
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "pos")]
+struct Positional {
+    #[hizli(positional, required)]
+    name: LitStr,
+    #[hizli(positional)]
+    ty: Option<Path>,
+    skip: bool,
+}
+
+fn positional(attr: Attribute) -> hizli::syn::Result<Positional> {
+    Positional::from_attrs_opt(&[attr]).map(Option::unwrap)
+}
+
+#[test]
+fn positional_arguments_are_parsed() {
+    let attr = positional(parse_quote!(#[pos("a", my::Type, skip)])).unwrap();
+    assert_eq!(attr.name.value(), "a");
+    assert_eq!(attr.ty.to_token_stream().to_string(), "my :: Type");
+    assert!(attr.skip);
+
+    let attr = positional(parse_quote!(#[pos("a", skip)])).unwrap();
+    assert!(attr.ty.is_none());
+
+    let attr = positional(parse_quote!(#[pos(skip, name = "b")])).unwrap();
+    assert_eq!(attr.name.value(), "b");
+}
+
+#[test]
+fn positional_arguments_are_validated() {
+    let err = positional(parse_quote!(#[pos(skip, "a")])).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Positional Arguments Must Precede Keyword Arguments"
+    );
+
+    let err = positional(parse_quote!(#[pos("a", b, "c")])).err().unwrap();
+    assert_eq!(err.to_string(), "Unexpected Positional Argument");
+
+    let err = positional(parse_quote!(#[pos("a", name = "b")]))
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "Duplicate Key `name`");
+}
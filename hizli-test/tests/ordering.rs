@@ -0,0 +1,140 @@
+use hizli::{
+    EnumBinding, NsAttr, Parse, StructBinding, assert_expands, assert_parse_err, bound_type_params,
+    impl_block,
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{Data, DeriveInput, Fields, FieldsUnnamed, Ident, LitInt, LitStr, Result, parse_quote},
+    testing::expand,
+};
+
+#[test]
+fn field_bindings_follow_declaration_order() {
+    let fields: FieldsUnnamed = parse_quote! { (u8, u16, u32) };
+    let binding = StructBinding::new(&Fields::Unnamed(fields));
+
+    let forward: Vec<_> = binding.iter().map(|fb| fb.ident().to_string()).collect();
+    assert_eq!(forward, ["binding_0", "binding_1", "binding_2"]);
+
+    let backward: Vec<_> = binding
+        .iter()
+        .rev()
+        .map(|fb| fb.ident().to_string())
+        .collect();
+    assert_eq!(backward, ["binding_2", "binding_1", "binding_0"]);
+}
+
+#[test]
+fn variant_bindings_follow_declaration_order() {
+    let input: DeriveInput = parse_quote! {
+        enum Foo { C { z: u8, a: u8 }, A, B(u8) }
+    };
+    let data = match input.data {
+        Data::Enum(e) => e,
+        _ => unreachable!(),
+    };
+    let binding = EnumBinding::new(&data);
+
+    let variants: Vec<_> = binding.iter().map(|vb| vb.ident().to_string()).collect();
+    assert_eq!(variants, ["C", "A", "B"]);
+
+    let last = binding.iter().next_back().unwrap();
+    assert_eq!(last.ident(), "B");
+
+    let first = binding.iter().next().unwrap();
+    let fields: Vec<_> = first.iter().map(|fb| fb.ident().to_string()).collect();
+    assert_eq!(fields, ["z", "a"]);
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Literal {
+    Z(LitStr),
+    A(LitInt),
+    M(Ident),
+}
+
+#[test]
+fn expected_one_of_follows_declaration_order() {
+    assert_parse_err!(
+        Literal,
+        { + },
+        "Error Parsing: Literal, Expected One Of: LitStr, LitInt, Ident"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct Keys {
+    #[hizli(required)]
+    zeta: bool,
+    #[hizli(required)]
+    alpha: bool,
+}
+
+#[test]
+fn ns_attr_keys_follow_field_order() {
+    assert_eq!(Keys::KEYS, ["zeta", "alpha"]);
+
+    let attrs = [parse_quote!(#[myattr()])];
+    let err = Keys::from_attrs_opt(&attrs).err().unwrap();
+    assert_eq!(err.to_string(), "Missing Required Keys `zeta`, `alpha`");
+}
+
+fn arms(input: DeriveInput) -> Result<TokenStream> {
+    let (binding, ident, _) = EnumBinding::try_from_input(&input, "Arms")?;
+    let arms = binding.iter().map(|vb| {
+        let pat = vb.qualified_pattern(&quote! { #ident });
+        let name = vb.ident().to_string();
+        quote! { #pat => #name, }
+    });
+    Ok(quote! {
+        fn name(value: &#ident) -> &'static str {
+            match value { #(#arms)* }
+        }
+    })
+}
+
+#[test]
+fn match_arms_follow_declaration_order() {
+    let input = quote! { enum Foo { C { z: u8, a: u8 }, A, B(u8) } };
+    let first = expand(arms, input.clone());
+    assert_eq!(first, expand(arms, input));
+    assert_expands!(
+        arms,
+        {
+            enum Foo {
+                C { z: u8, a: u8 },
+                A,
+                B(u8),
+            }
+        },
+        r#"
+        fn name(value: &Foo) -> &'static str {
+            match value {
+                Foo::C { z, a } => "C",
+                Foo::A => "A",
+                Foo::B(binding_0) => "B",
+            }
+        }
+        "#
+    );
+}
+
+#[test]
+fn where_predicates_follow_declaration_order() {
+    let input: DeriveInput = parse_quote! {
+        struct Foo<B, A: Copy> where B: Default, A: Clone { b: B, a: A }
+    };
+    let generics = bound_type_params(&input.generics, &parse_quote!(Debug));
+    let tokens = impl_block(&input.ident, &generics, Some(quote! { Trait }), quote! {});
+    assert_eq!(
+        tokens.to_string(),
+        quote! {
+            #[automatically_derived]
+            impl<B: Debug, A: Copy + Debug> Trait for Foo<B, A> where B: Default, A: Clone {}
+        }
+        .to_string()
+    );
+}
@@ -0,0 +1,90 @@
+//! Everything in `hizli-core` besides `out!` runs on `proc_macro2`'s fallback,
+//! so none of these tests need a proc-macro host.
+
+use hizli::{
+    EnumBinding, NsAttr, StructBinding, derive_span, emit_warning, error_output,
+    proc_macro2::Span,
+    quote::quote,
+    syn::{DeriveInput, Error, LitStr, parse_quote},
+    warning,
+};
+
+#[derive(Clone, NsAttr)]
+#[hizli(ns = "myattr", inherit)]
+struct MyAttr {
+    skip: bool,
+    rename: Option<LitStr>,
+}
+
+fn input() -> DeriveInput {
+    parse_quote! {
+        #[myattr(skip)]
+        enum Shape {
+            Circle { #[myattr(rename = "r")] radius: f32 },
+            Square(f32),
+        }
+    }
+}
+
+#[test]
+fn bindings_generate_patterns() {
+    let input = input();
+    let (binding, ident, _) = EnumBinding::try_from_input(&input, "MyMacro").unwrap();
+    let patterns: Vec<_> = binding
+        .variant_bindings()
+        .iter()
+        .map(|vb| vb.qualified_pattern(&quote! { #ident }).to_string())
+        .collect();
+    assert_eq!(
+        patterns,
+        ["Shape :: Circle { radius }", "Shape :: Square (binding_0)"]
+    );
+}
+
+#[test]
+fn bindings_generate_constructors() {
+    let fields = parse_quote!({ a: u8, b: u8 });
+    let binding = StructBinding::new(&hizli::syn::Fields::Named(fields));
+    assert_eq!(binding.reconstruct().to_string(), "{ a , b }");
+}
+
+#[test]
+fn ns_attr_resolves_fields() {
+    let input = input();
+    let container = MyAttr::from_attrs_opt(&input.attrs).unwrap();
+    let (binding, _, _) = EnumBinding::try_from_input(&input, "MyMacro").unwrap();
+    let circle = &binding.variant_bindings()[0];
+
+    let resolved = MyAttr::resolve_fields(container.as_ref(), circle.field_bindings()).unwrap();
+    let (_, attr) = &resolved[0];
+    let attr = attr.as_ref().unwrap();
+    assert!(attr.skip);
+    assert_eq!(attr.rename.as_ref().unwrap().value(), "r");
+}
+
+#[test]
+fn warnings_fall_back_to_the_deprecation_shim() {
+    let tokens = warning(Span::call_site(), "Something Is Off");
+    assert!(tokens.to_string().contains("Something Is Off"));
+}
+
+#[test]
+fn emit_warning_formats_its_message() {
+    let field = "radius";
+    let tokens = emit_warning!(Span::call_site(), "Field `{}` Is Never Parsed", field);
+    assert_eq!(
+        tokens.to_string(),
+        warning(Span::call_site(), "Field `radius` Is Never Parsed").to_string(),
+    );
+}
+
+#[test]
+fn errors_fall_back_to_compile_error() {
+    let tokens = error_output(Error::new(Span::call_site(), "Something Failed"));
+    assert!(tokens.to_string().contains("compile_error"));
+}
+
+#[test]
+fn derive_span_is_the_call_site() {
+    assert!(derive_span().source_text().is_none());
+}
@@ -0,0 +1,55 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Expr, Ident, LitInt, Token},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Pair {
+    left: Ident,
+    _comma: Token![,],
+    right: Ident,
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Value {
+    Int(LitInt),
+    Ident(Ident),
+}
+
+#[test]
+fn parsed_values_are_returned() {
+    let pair = assert_parses!(Pair, { a, b });
+    assert_eq!(pair.left, "a");
+    assert_eq!(pair.right, "b");
+}
+
+#[test]
+fn parsed_values_are_compared_by_tokens() {
+    assert_parses!(Expr, { 1 + 2 * 3 }, { 1 + 2 * 3 });
+}
+
+#[test]
+fn errors_are_compared_with_their_span() {
+    assert_parse_err!(Pair, { a b }, "expected `,`", at = "b");
+    assert_parse_err!(
+        Value,
+        { "text" },
+        "Error Parsing: Value, Expected One Of: LitInt, Ident",
+        at = "\"text\""
+    );
+}
+
+#[test]
+#[should_panic(expected = "Points At The Wrong Tokens")]
+fn wrong_spans_are_reported() {
+    assert_parse_err!(Pair, { a b }, "expected `,`", at = "a");
+}
+
+#[test]
+#[should_panic(expected = "Parsed Successfully")]
+fn successful_parses_are_reported() {
+    assert_parse_err!(Pair, { a, b }, "expected `,`");
+}
@@ -0,0 +1,52 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Ident, LitInt, Token},
+};
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, context)]
+struct Assign {
+    name: Ident,
+    _eq: Token![=],
+    value: Value,
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, context)]
+enum Value {
+    Int(Token![+], LitInt),
+    Neg(Token![-], LitInt),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Plain {
+    name: Ident,
+}
+
+#[test]
+fn context_names_the_failing_field() {
+    assert_parses!(Assign, { a = +1 });
+    assert_parse_err!(
+        Assign,
+        { 1 },
+        "While Parsing Field `name` Of `Assign`: expected identifier"
+    );
+}
+
+#[test]
+fn context_nests_through_fields_and_variants() {
+    assert_parse_err!(
+        Assign,
+        { a = -b },
+        "While Parsing Field `value` Of `Assign`: While Parsing Field `1` Of `Value::Neg`: expected integer literal"
+    );
+}
+
+#[test]
+fn errors_are_unchanged_without_context() {
+    assert_parse_err!(Plain, { 1 }, "expected identifier");
+}
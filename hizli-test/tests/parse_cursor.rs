@@ -0,0 +1,57 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Ident, LitInt, Token, token::Brace},
+};
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, cursor)]
+enum Item {
+    Fn(Token![fn], Ident),
+    Struct(Token![struct], Ident),
+    Neg(Token![-], LitInt),
+    Path(Token![::], Ident),
+    Colon(Token![:], Ident),
+    #[hizli(delimited = brace)]
+    Block(Brace, Ident),
+    Name(Ident),
+    Int(LitInt),
+    Again(Token![fn], LitInt),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, cursor)]
+enum Single {
+    Let(Token![let], Ident),
+}
+
+#[test]
+fn cursor_dispatch_chooses_the_matching_variant() {
+    assert!(matches!(assert_parses!(Item, { fn a }), Item::Fn(..)));
+    assert!(matches!(
+        assert_parses!(Item, { struct a }),
+        Item::Struct(..)
+    ));
+    assert!(matches!(assert_parses!(Item, { -1 }), Item::Neg(..)));
+    assert!(matches!(assert_parses!(Item, { { a } }), Item::Block(..)));
+    assert!(matches!(assert_parses!(Item, { a }), Item::Name(..)));
+    assert!(matches!(assert_parses!(Item, { 1 }), Item::Int(..)));
+    assert!(matches!(assert_parses!(Single, { let a }), Single::Let(..)));
+}
+
+#[test]
+fn cursor_dispatch_keeps_declaration_order() {
+    assert!(matches!(assert_parses!(Item, { ::a }), Item::Path(..)));
+    assert!(matches!(assert_parses!(Item, { :a }), Item::Colon(..)));
+    assert_parse_err!(Item, { fn 1 }, "expected identifier");
+}
+
+#[test]
+fn cursor_dispatch_keeps_the_fallback_error() {
+    assert_parse_err!(
+        Item,
+        { + },
+        "Error Parsing: Item, Expected One Of: Token! [fn], Token! [struct], Token! [-], Token! [::], Token! [:], { ... }, Ident, LitInt, Token! [fn]"
+    );
+}
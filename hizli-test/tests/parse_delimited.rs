@@ -0,0 +1,118 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Ident, LitInt, Token, token},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli, delimited = brace)]
+struct Block {
+    brace: token::Brace,
+    name: Ident,
+    _colon: Token![:],
+    value: LitInt,
+}
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli, delimited = paren)]
+struct Group(Ident, Ident);
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Item {
+    #[hizli(delimited = bracket)]
+    List(token::Bracket, LitInt, Token![,], LitInt),
+    #[hizli(delimited = paren)]
+    Unit {},
+    Name(Ident),
+}
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Func {
+    _fn: Token![fn],
+    name: Ident,
+    #[hizli(delimited = paren)]
+    param: Option<Ident>,
+    #[hizli(delimited = brace)]
+    brace: token::Brace,
+    #[hizli(delimited = brace)]
+    body: LitInt,
+    _semi: Token![;],
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Stmt {
+    Block(#[hizli(delimited = brace)] Ident),
+    Name(Ident),
+}
+
+#[test]
+fn fields_are_parsed_inside_the_delimiter() {
+    let block = assert_parses!(Block, { { answer: 42 } });
+    assert_eq!(block.name, "answer");
+    assert_eq!(block.value.base10_digits(), "42");
+    assert!(
+        block
+            .brace
+            .span
+            .join()
+            .source_text()
+            .unwrap()
+            .starts_with('{')
+    );
+
+    let group = assert_parses!(Group, { (a b) });
+    assert_eq!(group.0, "a");
+    assert_eq!(group.1, "b");
+}
+
+#[test]
+fn missing_delimiters_are_rejected() {
+    assert_parse_err!(Block, { answer: 42 }, "expected curly braces", at = "answer");
+}
+
+#[test]
+fn leftover_content_is_rejected() {
+    assert_parse_err!(Group, { (a b c) }, "unexpected token, expected `)`", at = "c");
+}
+
+#[test]
+fn variants_peek_their_delimiter() {
+    assert!(matches!(assert_parses!(Item, { [1, 2] }), Item::List(..)));
+    assert!(matches!(assert_parses!(Item, { () }), Item::Unit {}));
+    assert!(matches!(assert_parses!(Item, { name }), Item::Name(_)));
+    assert_parse_err!(
+        Item,
+        { 1 },
+        "Error Parsing: Item, Expected One Of: [ ... ], ( ... ), Ident"
+    );
+}
+
+#[test]
+fn delimited_fields_are_parsed_inside_their_group() {
+    let func = assert_parses!(Func, { fn f(x) { 1 }; });
+    assert_eq!(func.name, "f");
+    assert_eq!(func.param.unwrap(), "x");
+    assert_eq!(func.body.base10_digits(), "1");
+    assert_eq!(func.brace.span.join().source_text().unwrap(), "{ 1 }");
+
+    assert!(
+        assert_parses!(Func, {
+            fn f() {
+                1
+            };
+        })
+        .param
+        .is_none()
+    );
+    assert_parse_err!(Func, { fn f() { 1 2 }; }, "unexpected token, expected `}`", at = "2");
+}
+
+#[test]
+fn delimited_first_fields_peek_their_delimiter() {
+    assert!(matches!(assert_parses!(Stmt, { { a } }), Stmt::Block(_)));
+    assert!(matches!(assert_parses!(Stmt, { a }), Stmt::Name(_)));
+}
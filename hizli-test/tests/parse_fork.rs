@@ -0,0 +1,45 @@
+use hizli::{
+    Parse, assert_parses,
+    syn::{Ident, LitInt, LitStr, Token},
+    testing::parse_tokens,
+};
+use hizli_test::ui::messages;
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, fork)]
+enum Setting {
+    Int(Ident, Token![=], LitInt),
+    Str(Ident, Token![=], LitStr),
+}
+
+#[test]
+fn fork_takes_the_first_variant_that_parses() {
+    assert!(matches!(
+        assert_parses!(Setting, { a = 1 }),
+        Setting::Int(..)
+    ));
+    assert!(matches!(
+        assert_parses!(Setting, { a = "b" }),
+        Setting::Str(..)
+    ));
+}
+
+#[test]
+fn fork_combines_every_variant_error() {
+    let err = match parse_tokens::<Setting>(hizli::quote::quote! { a = true }) {
+        Ok(_) => panic!("Expected Error"),
+        Err(err) => err,
+    };
+    assert_eq!(
+        messages(&err),
+        [
+            "Error Parsing: Setting, Expected One Of: Ident, Ident",
+            "expected integer literal",
+            "expected string literal",
+        ]
+    );
+    for err in err.into_iter().skip(1) {
+        assert_eq!(err.span().source_text().as_deref(), Some("true"));
+    }
+}
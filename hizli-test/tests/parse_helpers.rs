@@ -0,0 +1,70 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Ident, LitInt, LitStr, Token, parse::Parse},
+};
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, helpers)]
+enum Value {
+    Int(LitInt),
+    Str { lit: LitStr },
+    Pair(Ident, Token![=], Ident),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, helpers)]
+enum Wrapped<T: Parse> {
+    Marked(Token![#], T),
+    Plain(Ident),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, helpers)]
+enum Expr {
+    Neg(Token![-], Box<Self>),
+    Int(LitInt),
+}
+
+impl Expr {
+    /// Shares the name of a helper, which must not collide with it.
+    fn __hizli_parse_1(&self) -> bool {
+        matches!(self, Self::Int(_))
+    }
+}
+
+#[test]
+fn helpers_parse_every_variant() {
+    assert!(matches!(assert_parses!(Value, { 1 }), Value::Int(lit) if lit.base10_digits() == "1"));
+    assert!(matches!(assert_parses!(Value, { "a" }), Value::Str { lit } if lit.value() == "a"));
+    assert!(
+        matches!(assert_parses!(Value, { a = b }), Value::Pair(a, _, b) if a == "a" && b == "b")
+    );
+}
+
+#[test]
+fn helpers_keep_the_fallback_error() {
+    assert_parse_err!(
+        Value,
+        { + },
+        "Error Parsing: Value, Expected One Of: LitInt, LitStr, Ident",
+        at = "+"
+    );
+}
+
+#[test]
+fn helpers_support_generics() {
+    assert!(matches!(
+        assert_parses!(Wrapped<LitInt>, { # 1 }),
+        Wrapped::Marked(_, lit) if lit.base10_digits() == "1"
+    ));
+}
+
+#[test]
+fn helpers_are_not_methods_of_the_input() {
+    let expr = assert_parses!(Expr, { --1 });
+    assert!(!expr.__hizli_parse_1());
+    assert!(matches!(expr, Expr::Neg(_, inner) if matches!(*inner, Expr::Neg(..))));
+}
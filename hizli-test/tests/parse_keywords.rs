@@ -0,0 +1,74 @@
+use hizli::{
+    Parse,
+    proc_macro2::Span,
+    syn::{Ident, Token, custom_keyword},
+};
+
+mod kw {
+    use super::custom_keyword;
+
+    custom_keyword!(select);
+    custom_keyword!(from);
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, keywords)]
+struct Select {
+    #[hizli(keyword)]
+    select: kw::select,
+    column: Ident,
+    #[hizli(keyword)]
+    from: kw::from,
+    table: Ident,
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, keywords, helpers)]
+enum Stmt {
+    Select(#[hizli(keyword)] kw::select, Ident),
+    Let(Token![let], Ident, Token![=], Ident),
+    Again(#[hizli(keyword)] kw::select, #[hizli(keyword)] kw::from),
+    Expr(Ident),
+}
+
+mod m {
+    #[allow(non_camel_case_types)]
+    pub type lower = hizli::syn::LitInt;
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, keywords)]
+struct Typed {
+    let_token: Token![let],
+    n: m::lower,
+}
+
+fn ident(name: &str) -> Ident {
+    Ident::new(name, Span::call_site())
+}
+
+#[test]
+fn keywords_list_custom_keywords_in_declaration_order() {
+    assert_eq!(Select::KEYWORDS, ["select", "from"]);
+    assert_eq!(Stmt::KEYWORDS, ["select", "let", "from"]);
+}
+
+#[test]
+fn lowercase_type_paths_are_not_keywords() {
+    assert_eq!(Typed::KEYWORDS, ["let"]);
+    assert!(!Typed::is_keyword(&ident("lower")));
+}
+
+#[test]
+fn is_keyword_classifies_identifiers() {
+    assert!(Select::is_keyword(&ident("select")));
+    assert!(Stmt::is_keyword(&ident("from")));
+    assert!(!Select::is_keyword(&ident("column")));
+    assert!(!Select::is_keyword(&Ident::new_raw(
+        "select",
+        Span::call_site()
+    )));
+}
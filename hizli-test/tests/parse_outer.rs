@@ -0,0 +1,40 @@
+use hizli::{
+    Parse, assert_parses,
+    syn::{Attribute, Ident, Token},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Item {
+    #[hizli(outer)]
+    attrs: Vec<Attribute>,
+    _fn: Token![fn],
+    name: Ident,
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Decl {
+    Const(Token![const], #[hizli(outer)] Vec<Attribute>, Ident),
+}
+
+#[test]
+fn outer_fields_capture_attributes_and_doc_comments() {
+    let item = assert_parses!(Item, {
+        /// Docs.
+        #[inline]
+        fn a
+    });
+    assert_eq!(item.name, "a");
+    assert_eq!(item.attrs.len(), 2);
+    assert!(item.attrs[0].path().is_ident("doc"));
+    assert!(item.attrs[1].path().is_ident("inline"));
+}
+
+#[test]
+fn outer_fields_are_empty_without_attributes() {
+    assert!(assert_parses!(Item, { fn a }).attrs.is_empty());
+    let Decl::Const(_, attrs, _) = assert_parses!(Decl, { const #[a] #[b] c });
+    assert_eq!(attrs.len(), 2);
+}
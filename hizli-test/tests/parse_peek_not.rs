@@ -0,0 +1,65 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Ident, Token},
+};
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Operand {
+    #[hizli(peek_not = Token![::])]
+    Local(Ident),
+    Path(Ident, Token![::], Ident),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli, cursor)]
+enum Head {
+    #[hizli(peek_not = Token![=])]
+    Let(Token![let], Ident),
+    Assign(Token![let], Token![=], Ident),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Root {
+    #[hizli(peek_not = Token![*])]
+    Name(Token![::], Ident),
+    Glob(Token![::], Token![*]),
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Name {
+    #[hizli(peek_not = Token![::])]
+    ident: Ident,
+}
+
+#[test]
+fn variants_are_skipped_when_followed_by_the_token() {
+    assert!(matches!(assert_parses!(Operand, { a }), Operand::Local(_)));
+    assert!(matches!(
+        assert_parses!(Operand, { a::b }),
+        Operand::Path(..)
+    ));
+    assert!(matches!(assert_parses!(Head, { let a }), Head::Let(..)));
+    assert!(matches!(
+        assert_parses!(Head, { let = a }),
+        Head::Assign(..)
+    ));
+}
+
+#[test]
+fn variants_peek_after_a_first_field_of_several_tokens() {
+    assert!(matches!(assert_parses!(Root, { ::a }), Root::Name(..)));
+    assert!(matches!(assert_parses!(Root, { ::* }), Root::Glob(..)));
+}
+
+#[test]
+fn fields_reject_the_following_token() {
+    assert_eq!(assert_parses!(Name, { a }).ident, "a");
+    assert_parse_err!(Name, { a::b }, "Unexpected `::`");
+}
@@ -0,0 +1,56 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Expr, Ident, Token, Type},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Param {
+    name: Ident,
+    _colon: Token![:],
+    #[allow(dead_code)]
+    ty: Type,
+    eq: Option<Token![=]>,
+    #[hizli(requires = eq)]
+    default: Option<Expr>,
+}
+
+#[allow(dead_code)]
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+enum Binding {
+    Let(
+        Token![let],
+        Ident,
+        Option<Token![=]>,
+        #[hizli(requires = 2)] Option<Expr>,
+    ),
+}
+
+#[test]
+fn dependent_fields_parse_when_required_field_is_some() {
+    let param = assert_parses!(Param, { x: u8 = 1 + 2 });
+    assert!(param.eq.is_some());
+    assert!(param.default.is_some());
+    assert_eq!(param.name, "x");
+}
+
+#[test]
+fn dependent_fields_are_none_otherwise() {
+    let param = assert_parses!(Param, { x: u8 });
+    assert!(param.eq.is_none());
+    assert!(param.default.is_none());
+}
+
+#[test]
+fn dependent_fields_are_not_optional_once_required() {
+    assert_parse_err!(Param, { x: u8 = }, "unexpected end of input, expected an expression");
+}
+
+#[test]
+fn variants_support_dependent_fields() {
+    let Binding::Let(_, _, eq, value) = assert_parses!(Binding, { let x = y });
+    assert!(eq.is_some() && value.is_some());
+    let Binding::Let(_, _, eq, value) = assert_parses!(Binding, { let x });
+    assert!(eq.is_none() && value.is_none());
+}
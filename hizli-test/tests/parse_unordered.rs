@@ -0,0 +1,74 @@
+use hizli::{
+    Parse, assert_parse_err, assert_parses,
+    syn::{Ident, LitInt, LitStr, Path},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli, unordered)]
+struct Options {
+    name: Option<LitStr>,
+    from: Option<Path>,
+    skip: bool,
+    #[hizli(default = hizli::syn::parse_quote!(8))]
+    width: LitInt,
+    count: Option<LitInt>,
+    #[hizli(default = Some(hizli::syn::parse_quote!(3)))]
+    depth: Option<LitInt>,
+    #[hizli(default)]
+    label: Option<LitStr>,
+    #[hizli(skip)]
+    marker: Vec<Ident>,
+}
+
+#[test]
+fn clauses_parse_in_any_order() {
+    let options = assert_parses!(Options, { skip, from = a::b, name = "x" });
+    assert_eq!(options.name.unwrap().value(), "x");
+    assert!(options.from.unwrap().segments.len() == 2);
+    assert!(options.skip);
+    assert!(options.count.is_none());
+    assert!(options.marker.is_empty());
+}
+
+#[test]
+fn absent_clauses_use_their_defaults() {
+    let options = assert_parses!(Options, {});
+    assert!(options.name.is_none());
+    assert!(!options.skip);
+    assert_eq!(options.width.base10_parse::<u8>().unwrap(), 8);
+    assert_eq!(options.depth.unwrap().base10_parse::<u8>().unwrap(), 3);
+    assert!(options.label.is_none());
+}
+
+#[test]
+fn optional_clauses_override_their_defaults() {
+    let options = assert_parses!(Options, { depth = 5, label = "x" });
+    assert_eq!(options.depth.unwrap().base10_parse::<u8>().unwrap(), 5);
+    assert_eq!(options.label.unwrap().value(), "x");
+}
+
+#[test]
+fn flags_accept_an_explicit_bool() {
+    assert!(assert_parses!(Options, { skip = true }).skip);
+    assert!(!assert_parses!(Options, { skip = false, width = 3 }).skip);
+}
+
+#[test]
+fn valued_keys_require_the_eq() {
+    assert_parse_err!(Options, { from std::fmt }, "expected `=`", at = "std");
+}
+
+#[test]
+fn duplicate_keys_are_rejected() {
+    assert_parse_err!(Options, { skip, skip }, "Duplicate Key `skip`", at = "skip");
+}
+
+#[test]
+fn unknown_keys_are_rejected() {
+    assert_parse_err!(
+        Options,
+        { nam = "x" },
+        "Unknown Key `nam`, Did You Mean `name`? (Accepted Keys: `name`, `from`, `skip`, `width`, `count`, `depth`, `label`)",
+        at = "nam"
+    );
+}
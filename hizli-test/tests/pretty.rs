@@ -0,0 +1,21 @@
+use hizli::{ToPrettyString, quote::quote};
+
+#[test]
+fn items_are_formatted_as_source() {
+    let tokens = quote! { impl Marker for Foo { fn mark(&self) -> u8 { 0 } } };
+    assert_eq!(
+        tokens.to_pretty_string().unwrap(),
+        "impl Marker for Foo {\n    fn mark(&self) -> u8 {\n        0\n    }\n}\n"
+    );
+}
+
+#[test]
+fn syntax_nodes_are_formatted() {
+    let item: hizli::syn::ItemStruct = hizli::syn::parse_quote! { struct Unit; };
+    assert_eq!(item.to_pretty_string().unwrap(), "struct Unit;\n");
+}
+
+#[test]
+fn non_items_are_rejected() {
+    assert!(quote! { 1 + 2 }.to_pretty_string().is_err());
+}
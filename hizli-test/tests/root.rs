@@ -0,0 +1,74 @@
+extern crate alloc;
+
+use hizli::{
+    NsAttr, Parse, ToTokens,
+    quote::{ToTokens as _, quote},
+    syn::{Ident, LitInt, Token, parse_quote},
+    testing::parse_tokens,
+};
+
+/// Everything generated code needs from the standard library, under another name.
+mod facade {
+    pub use ::std::{default, iter, result, vec};
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli, root = crate::facade, fork)]
+enum Value {
+    Int(LitInt),
+    Name(Ident),
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli, root = crate::facade, delimited = bracket)]
+struct List {
+    #[hizli(separator = Token![,])]
+    items: Vec<Value>,
+    #[hizli(skip)]
+    _marker: (),
+}
+
+#[derive(NsAttr)]
+#[hizli(crate = ::hizli, root = ::std, ns = "demo", lenient)]
+struct Demo {
+    flag: bool,
+    name: Option<Ident>,
+    count: Option<LitInt>,
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli, root = ::core, alloc = ::alloc)]
+struct CoreList {
+    #[hizli(separator = Token![,])]
+    items: Vec<Value>,
+}
+
+#[derive(NsAttr)]
+#[hizli(crate = ::hizli, root = core, alloc = alloc, ns = "bare", lenient)]
+struct Bare {
+    flag: bool,
+}
+
+#[test]
+fn generated_code_uses_the_root() {
+    let list = parse_tokens::<List>(quote! { [1, a] }).unwrap();
+    assert_eq!(list.to_token_stream().to_string(), "[1 , a]");
+
+    let demo = Demo::from_attrs_opt(&[parse_quote!(#[demo(flag, count = 3, other)])])
+        .unwrap()
+        .unwrap();
+    assert!(demo.flag);
+    assert!(demo.name.is_none());
+    assert_eq!(demo.count.unwrap().base10_digits(), "3");
+}
+
+#[test]
+fn core_root_reaches_vec_through_alloc() {
+    let list = parse_tokens::<CoreList>(quote! { 1, a, 2 }).unwrap();
+    assert_eq!(list.to_token_stream().to_string(), "1 , a , 2");
+
+    let bare = Bare::from_attrs_opt(&[parse_quote!(#[bare(flag, other)])])
+        .unwrap()
+        .unwrap();
+    assert!(bare.flag);
+}
@@ -0,0 +1,60 @@
+use hizli::{
+    ArbitrarySyntax, Parse,
+    proc_macro2::TokenStream,
+    quote::{ToTokens, quote},
+    syn::{Ident, LitInt, LitStr, Token},
+    testing::{ArbitrarySyntax as _, Rng, roundtrip},
+};
+
+#[derive(Parse, ArbitrarySyntax)]
+#[hizli(crate = ::hizli)]
+struct Assign {
+    name: Ident,
+    eq: Token![=],
+    value: Value,
+    semi: Token![;],
+    #[hizli(skip, default = 7)]
+    extra: u8,
+}
+
+#[derive(Parse, ArbitrarySyntax)]
+#[hizli(crate = ::hizli)]
+enum Value {
+    Str(LitStr),
+    Int(LitInt),
+    Neg(Token![-], Box<Value>),
+}
+
+impl ToTokens for Assign {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            name,
+            eq,
+            value,
+            semi,
+            ..
+        } = self;
+        tokens.extend(quote! { #name #eq #value #semi });
+    }
+}
+
+impl ToTokens for Value {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Str(lit) => lit.to_tokens(tokens),
+            Self::Int(lit) => lit.to_tokens(tokens),
+            Self::Neg(minus, inner) => tokens.extend(quote! { #minus #inner }),
+        }
+    }
+}
+
+#[test]
+fn derived_grammar_roundtrips() {
+    roundtrip::<Assign>(256);
+}
+
+#[test]
+fn skipped_fields_use_defaults() {
+    let assign = Assign::arbitrary(&mut Rng::new(3));
+    assert_eq!(assign.extra, 7);
+}
@@ -0,0 +1,60 @@
+use hizli::{
+    Parse, Separated, assert_parse_err, assert_parses, parse_separator,
+    quote::ToTokens,
+    syn::{
+        Ident, LitInt, Token,
+        parse::{ParseStream, Parser},
+    },
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Call {
+    name: Ident,
+    _colon: Token![:],
+    args: Separated<LitInt>,
+}
+
+#[test]
+fn trailing_separators_are_optional() {
+    let call = assert_parses!(Call, { sum: 1, 2, 3 });
+    assert_eq!(call.args.len(), 3);
+    let call = assert_parses!(Call, { sum: 1, 2, 3, });
+    assert_eq!(call.args.len(), 3);
+    assert!(call.args.trailing_punct());
+    assert_eq!(call.name, "sum");
+}
+
+#[test]
+fn empty_lists_parse() {
+    let call = assert_parses!(Call, { sum: });
+    assert!(call.args.is_empty());
+}
+
+#[test]
+fn lists_print_as_parsed() {
+    let call = assert_parses!(Call, { sum: 1, 2, });
+    assert_eq!(call.args.to_token_stream().to_string(), "1 , 2 ,");
+}
+
+#[test]
+fn doubled_separators_are_rejected() {
+    assert_parse_err!(Call, { sum: 1,, 2 }, "expected integer literal", at = ",");
+}
+
+#[test]
+fn separator_loops_accept_a_trailing_separator() {
+    let parser = |input: ParseStream| {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse::<Ident>()?);
+            if !parse_separator::<Token![;]>(input)? {
+                break;
+            }
+        }
+        Ok(items)
+    };
+    assert_eq!(parser.parse_str("a; b").unwrap().len(), 2);
+    assert_eq!(parser.parse_str("a; b;").unwrap().len(), 2);
+    assert!(parser.parse_str("a b").is_err());
+}
@@ -0,0 +1 @@
+impl Marker for A {}
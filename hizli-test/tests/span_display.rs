@@ -0,0 +1,52 @@
+use hizli::{
+    render_error, span_excerpt, span_location,
+    syn::{self, Error, ItemStruct, spanned::Spanned},
+};
+
+const SOURCE: &str = "#[derive(Clone)]\nstruct Point { x: u8 }";
+
+fn parsed() -> ItemStruct {
+    syn::parse_str(SOURCE).unwrap()
+}
+
+#[test]
+fn locations_are_one_based() {
+    let location = span_location(parsed().ident.span());
+    assert!(location.ends_with(":2:8"), "{location}");
+}
+
+#[test]
+fn excerpts_underline_the_span() {
+    assert_eq!(
+        span_excerpt(parsed().ident.span(), SOURCE).unwrap(),
+        "  |\n2 | struct Point { x: u8 }\n  |        ^^^^^"
+    );
+}
+
+#[test]
+fn multiline_spans_are_underlined_to_the_end_of_the_line() {
+    let item = parsed();
+    assert_eq!(
+        span_excerpt(item.span(), SOURCE).unwrap(),
+        "  |\n1 | #[derive(Clone)]\n  | ^^^^^^^^^^^^^^^^"
+    );
+}
+
+#[test]
+fn excerpts_outside_the_source_are_none() {
+    assert!(span_excerpt(parsed().ident.span(), "struct").is_none());
+}
+
+#[test]
+fn errors_render_every_message() {
+    let item = parsed();
+    let mut err = Error::new(item.ident.span(), "Unsupported Struct");
+    err.combine(Error::new(item.fields.span(), "Unsupported Fields"));
+
+    let rendered = render_error(&err, SOURCE);
+    let mut lines = rendered.lines();
+    assert_eq!(lines.next(), Some("error: Unsupported Struct"));
+    assert!(lines.next().unwrap().ends_with(":2:8"));
+    assert!(rendered.contains("\nerror: Unsupported Fields\n"));
+    assert!(rendered.ends_with("  |              ^^^^^^^^^"));
+}
@@ -0,0 +1,131 @@
+use std::rc::Rc;
+
+use hizli::{
+    Separated, Spanable,
+    proc_macro2::Span,
+    span_or,
+    syn::{Block, Ident, ItemFn, LitInt, Token, parse::Parser, parse_str, punctuated::Punctuated},
+};
+
+#[allow(dead_code)]
+#[derive(Spanable)]
+#[hizli(crate = ::hizli)]
+struct Call {
+    callee: Box<Ident>,
+    args: Vec<Arg>,
+}
+
+#[allow(dead_code)]
+#[derive(Spanable)]
+#[hizli(crate = ::hizli)]
+enum Arg {
+    Int(LitInt),
+    Named(Option<Ident>, Token![=]),
+    Unit,
+}
+
+mod kw {
+    hizli::syn::custom_keyword!(select);
+}
+
+#[allow(dead_code)]
+#[derive(Spanable)]
+#[hizli(crate = ::hizli)]
+struct Select {
+    select: kw::select,
+    body: Block,
+}
+
+#[allow(dead_code)]
+#[derive(Spanable)]
+#[hizli(crate = ::hizli)]
+enum Item {
+    Fn(Box<ItemFn>),
+    Select(Select),
+}
+
+fn ident(name: &str) -> Ident {
+    parse_str(name).unwrap()
+}
+
+fn source(span: Span) -> String {
+    span.source_text().unwrap_or_default()
+}
+
+#[test]
+fn containers_delegate_to_their_elements() {
+    let ident = ident("a");
+    assert_eq!(source(Box::new(ident.clone()).spanable()), "a");
+    assert_eq!(source(Some(ident.clone()).spanable()), "a");
+    assert_eq!(source(vec![ident.clone()].spanable()), "a");
+
+    let list = Punctuated::<LitInt, Token![,]>::parse_terminated
+        .parse_str("1, 2, 3")
+        .unwrap();
+    let joined = list.spanable();
+    assert_eq!(joined.start(), list[0].span().start());
+    assert_eq!(joined.end(), list[2].span().end());
+    assert_eq!(source(Separated(list).spanable()), "1, 2, 3");
+}
+
+#[test]
+fn empty_containers_fall_back() {
+    let fallback = ident("fallback");
+    assert_eq!(source(span_or::<Ident>(None, fallback.span())), "fallback");
+    assert!(Vec::<Ident>::new().spanable().source_text().is_none());
+    assert!(None::<Ident>.spanable().source_text().is_none());
+}
+
+#[test]
+fn derived_types_delegate_through_containers() {
+    let call = Call {
+        callee: Box::new(ident("f")),
+        args: vec![Arg::Int(parse_str("1").unwrap()), Arg::Unit],
+    };
+    assert_eq!(source(call.spanable()), "f");
+    assert_eq!(source(call.args.spanable()), "1");
+}
+
+#[test]
+fn fields_that_are_only_spanned_fall_back_to_spanned() {
+    let select = Select {
+        select: parse_str("select").unwrap(),
+        body: parse_str("{}").unwrap(),
+    };
+    assert_eq!(source(select.spanable()), "select");
+    let item = Item::Fn(parse_str("fn f() {}").unwrap());
+    assert_eq!(source(item.spanable()), "fn f() {}");
+}
+
+trait Node: Spanable {
+    fn name(&self) -> &'static str;
+}
+
+impl Node for Call {
+    fn name(&self) -> &'static str {
+        "call"
+    }
+}
+
+impl Node for Arg {
+    fn name(&self) -> &'static str {
+        "arg"
+    }
+}
+
+#[test]
+fn trait_objects_produce_spans() {
+    let nodes: Vec<Box<dyn Node>> = vec![
+        Box::new(Arg::Int(parse_str("1").unwrap())),
+        Box::new(Call {
+            callee: Box::new(ident("f")),
+            args: Vec::new(),
+        }),
+    ];
+    let names: Vec<_> = nodes.iter().map(|node| node.name()).collect();
+    assert_eq!(names, ["arg", "call"]);
+    assert_eq!(source(nodes[1].spanable()), "f");
+
+    let shared: Vec<Rc<dyn Spanable>> = vec![Rc::new(ident("a")), Rc::new(Arg::Unit)];
+    assert_eq!(source(shared.spanable()), "a");
+}
@@ -0,0 +1,42 @@
+use hizli::{
+    Parse, Spanable, ToTokens,
+    quote::{ToTokens as _, quote},
+    syn::{Ident, LitInt, Token},
+    testing::parse_tokens,
+};
+
+#[derive(Parse, ToTokens, Spanable)]
+#[hizli(crate = ::hizli, spanned)]
+struct Assign {
+    name: Ident,
+    eq: Token![=],
+    value: Value,
+}
+
+#[derive(Parse, ToTokens, Spanable)]
+#[hizli(crate = ::hizli, spanned, fork)]
+enum Value {
+    Int(LitInt),
+    Name(Ident),
+}
+
+#[test]
+fn spanned_derives_behave_the_same() {
+    let assign = parse_tokens::<Assign>(quote! { a = 1 }).unwrap();
+    assert_eq!(assign.to_token_stream().to_string(), "a = 1");
+    assert_eq!(
+        assign.spanable().source_text(),
+        assign.name.span().source_text()
+    );
+    assert_eq!(
+        assign.value.spanable().source_text(),
+        assign
+            .value
+            .to_token_stream()
+            .into_iter()
+            .next()
+            .unwrap()
+            .span()
+            .source_text(),
+    );
+}
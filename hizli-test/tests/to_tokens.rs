@@ -0,0 +1,152 @@
+use hizli::{
+    DelimitedSpan, Parse, ToTokens,
+    proc_macro2::{Span, TokenStream},
+    quote::{ToTokens as _, quote},
+    syn::{Attribute, Ident, LitInt, LitStr, Token, punctuated::Punctuated, token},
+    testing::parse_tokens,
+};
+
+fn lit_str(value: &str, tokens: &mut TokenStream) {
+    LitStr::new(value, Span::call_site()).to_tokens(tokens);
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli)]
+struct Assign {
+    #[hizli(outer)]
+    attrs: Vec<Attribute>,
+    name: Ident,
+    eq: Token![=],
+    value: Value,
+    #[hizli(skip)]
+    extra: u8,
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli)]
+enum Value {
+    Int(LitInt),
+    Neg(Token![-], Box<Value>),
+    #[hizli(delimited = brace)]
+    Block(token::Brace, Ident),
+    #[hizli(delimited = paren)]
+    Group(DelimitedSpan, LitInt),
+}
+
+#[derive(ToTokens)]
+#[hizli(crate = ::hizli)]
+struct Label {
+    #[hizli(with = lit_str)]
+    text: String,
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli, delimited = bracket)]
+struct List {
+    #[hizli(separator = Token![,])]
+    items: Vec<LitInt>,
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli)]
+struct Stmts {
+    #[hizli(separator = Token![;], trailing)]
+    stmts: Punctuated<Ident, Token![,]>,
+}
+
+#[derive(Parse, ToTokens)]
+#[hizli(crate = ::hizli)]
+struct Func {
+    fn_token: Token![fn],
+    name: Ident,
+    #[hizli(delimited = paren)]
+    params: Option<Ident>,
+    #[hizli(delimited = brace)]
+    brace: token::Brace,
+    #[hizli(delimited = brace)]
+    body: Assign,
+}
+
+fn roundtrip(tokens: TokenStream) -> String {
+    let assign = parse_tokens::<Assign>(tokens).unwrap();
+    assert_eq!(assign.extra, 0);
+    assign.to_token_stream().to_string()
+}
+
+#[test]
+fn fields_print_in_declaration_order() {
+    assert_eq!(
+        roundtrip(quote! { #[doc = "a"] a = -1 }),
+        quote! { #[doc = "a"] a = -1 }.to_string()
+    );
+}
+
+#[test]
+fn delimited_variants_print_their_delimiter() {
+    assert_eq!(roundtrip(quote! { a = { b } }), "a = { b }");
+    assert_eq!(roundtrip(quote! { a = (1) }), "a = (1)");
+}
+
+#[test]
+fn with_fields_use_their_printer() {
+    let label = Label {
+        text: "hi".to_owned(),
+    };
+    assert_eq!(label.to_token_stream().to_string(), "\"hi\"");
+}
+
+#[test]
+fn separator_fields_interleave_their_separator() {
+    let list = parse_tokens::<List>(quote! { [1, 2, 3] }).unwrap();
+    assert_eq!(list.items.len(), 3);
+    assert_eq!(list.to_token_stream().to_string(), "[1 , 2 , 3]");
+    let reparsed = parse_tokens::<List>(list.to_token_stream()).unwrap();
+    assert_eq!(reparsed.items.len(), 3);
+
+    let empty = parse_tokens::<List>(quote! { [] }).unwrap();
+    assert_eq!(empty.to_token_stream().to_string(), "[]");
+
+    let trailing = parse_tokens::<List>(quote! { [1, 2,] }).unwrap();
+    assert_eq!(trailing.to_token_stream().to_string(), "[1 , 2]");
+}
+
+#[test]
+fn trailing_separator_follows_the_last_element() {
+    let stmts = Stmts {
+        stmts: [
+            Ident::new("a", Span::call_site()),
+            Ident::new("b", Span::call_site()),
+        ]
+        .into_iter()
+        .collect(),
+    };
+    assert_eq!(stmts.to_token_stream().to_string(), "a ; b ;");
+
+    let empty = Stmts {
+        stmts: Punctuated::new(),
+    };
+    assert!(empty.to_token_stream().is_empty());
+}
+
+#[test]
+fn trailing_separator_fields_parse_what_they_print() {
+    let stmts = parse_tokens::<Stmts>(quote! { a; b; }).unwrap();
+    assert_eq!(stmts.stmts.len(), 2);
+    let printed = stmts.to_token_stream();
+    assert_eq!(printed.to_string(), "a ; b ;");
+    let reparsed = parse_tokens::<Stmts>(printed).unwrap();
+    assert_eq!(reparsed.to_token_stream().to_string(), "a ; b ;");
+
+    assert!(parse_tokens::<Stmts>(quote! { a; b }).is_err());
+}
+
+#[test]
+fn delimited_fields_print_inside_their_group() {
+    let func = parse_tokens::<Func>(quote! { fn f(x) { a = 1 } }).unwrap();
+    assert_eq!(func.to_token_stream().to_string(), "fn f (x) { a = 1 }");
+
+    let func = parse_tokens::<Func>(quote! { fn f() { a = 1 } }).unwrap();
+    let printed = func.to_token_stream();
+    assert_eq!(printed.to_string(), "fn f () { a = 1 }");
+    assert!(parse_tokens::<Func>(printed).is_ok());
+}
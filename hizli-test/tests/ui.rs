@@ -0,0 +1,51 @@
+use hizli::{
+    EnumBinding, NsAttr,
+    syn::{Attribute, DeriveInput},
+};
+use hizli_test::ui::{assert_error, compile_fail};
+
+#[test]
+fn ns_attr_derive_diagnostics() {
+    compile_fail(env!("CARGO_MANIFEST_DIR"), "ns_attr");
+}
+
+#[test]
+fn parse_diagnostics() {
+    compile_fail(env!("CARGO_MANIFEST_DIR"), "parse");
+}
+
+#[test]
+fn shape_diagnostics() {
+    compile_fail(env!("CARGO_MANIFEST_DIR"), "shape");
+}
+
+#[test]
+fn spanned_diagnostics() {
+    compile_fail(env!("CARGO_MANIFEST_DIR"), "spanned");
+}
+
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct MyAttr {
+    _skip: bool,
+}
+
+#[test]
+fn errors_point_at_the_offending_key() {
+    let attr =
+        hizli::syn::parse::Parser::parse_str(Attribute::parse_outer, "#[myattr(_skip, _skip)]")
+            .unwrap();
+    assert_error(
+        MyAttr::from_attrs_opt(&attr),
+        "Duplicate Key `_skip`",
+        "_skip",
+    );
+}
+
+#[test]
+fn shape_errors_point_at_the_derive() {
+    let input: DeriveInput = hizli::syn::parse_str("struct Foo;").unwrap();
+    let err = EnumBinding::try_from_input(&input, "MyMacro").unwrap_err();
+    assert_eq!(err.to_string(), "Cannot #[derive(MyMacro)] On Struct");
+    assert!(err.span().source_text().is_none());
+}
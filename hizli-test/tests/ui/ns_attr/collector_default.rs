@@ -0,0 +1,11 @@
+use hizli::{NsAttr, syn::Ident};
+
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct MyAttr {
+    skip: bool,
+    #[hizli(unknown, default)]
+    unknown: Vec<Ident>,
+}
+
+fn main() {}
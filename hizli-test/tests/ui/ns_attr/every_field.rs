@@ -0,0 +1,12 @@
+use hizli::NsAttr;
+
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct MyAttr {
+    #[hizli(nested)]
+    skip: bool,
+    #[hizli(nested)]
+    quiet: bool,
+}
+
+fn main() {}
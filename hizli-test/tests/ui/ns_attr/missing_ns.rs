@@ -0,0 +1,8 @@
+use hizli::NsAttr;
+
+#[derive(NsAttr)]
+struct MyAttr {
+    skip: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use hizli::NsAttr;
+
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct MyAttr {
+    #[hizli(nested)]
+    skip: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use hizli::NsAttr;
+
+#[derive(NsAttr)]
+#[hizli(ns = "myattr")]
+struct MyAttr {
+    #[hizli(required, default)]
+    skip: bool,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use hizli::{
+    Parse,
+    syn::{Ident, LitInt},
+};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli)]
+struct Assign {
+    #[hizli(skip, skip)]
+    name: Ident,
+    #[hizli(skip, skip)]
+    value: LitInt,
+}
+
+fn main() {}
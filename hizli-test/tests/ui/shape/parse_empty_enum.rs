@@ -0,0 +1,6 @@
+use hizli::Parse;
+
+#[derive(Parse)]
+enum Never {}
+
+fn main() {}
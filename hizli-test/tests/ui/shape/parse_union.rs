@@ -0,0 +1,9 @@
+use hizli::Parse;
+
+#[derive(Clone, Copy, Parse)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use hizli::{Parse, syn::Ident};
+
+#[derive(Parse)]
+#[hizli(crate = ::hizli, spanned)]
+struct Assign {
+    name: Ident,
+    value: u8,
+}
+
+fn main() {}
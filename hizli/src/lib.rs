@@ -88,7 +88,7 @@
 //!
 //! # Procedural Macros: `#[derive(Parse)]` and `#[derive(Spanable)]`
 //!
-//! This crate provides two custom derive macros that automatically implement
+//! This crate provides custom derive macros that automatically implement
 //! common parsing and span-extraction traits for structs and enums using
 //! the [`syn`](https://docs.rs/syn) and [`quote`](https://docs.rs/quote) crates.
 //!
@@ -133,7 +133,7 @@
 //!
 //! ### `#[derive(Spanable)]`
 //!
-//! Implements the `span(&self)` method.
+//! Implements the [`Spanable`] trait, whose `spanable(&self)` method returns a span.
 //!
 //! - For **structs**, it returns the span of the first field if one exists,
 //!   or the call-site span if the struct has no fields.  
@@ -153,8 +153,41 @@
 //! }
 //!
 //! let leaf = Node::Other;
-//! let span = leaf.span();
+//! let span = leaf.spanable();
 //! ```
+//!
+//! ---
+//!
+//! ### `#[derive(ToTokens)]`
+//!
+//! Implements [`quote::ToTokens`], printing each field in declaration order, so a type
+//! prints back the way `#[derive(Parse)]` reads it.
+//!
+//! ---
+//!
+//! ### `#[hizli(...)]`
+//!
+//! Every derive understands the same attribute namespace:
+//!
+//! - `#[hizli(crate = path)]` on the type: generated code reaches `syn`, `proc_macro2` and `quote`
+//!   through `path::syn`, `path::proc_macro2` and `path::quote`, e.g. `#[hizli(crate = ::hizli)]`.
+//! - `#[hizli(root = path)]` on the type: generated code reaches the standard library through
+//!   `path` instead of `::core` and `::std`, e.g. `#[hizli(root = ::std)]` or a facade crate.
+//! - `#[hizli(alloc = path)]` on the type: generated code reaches `Vec` through `path` instead of
+//!   the root, e.g. `#[hizli(root = ::core, alloc = ::alloc)]` with `extern crate alloc`.
+//! - `#[hizli(spanned)]` on the type: the derived `Parse`, `ToTokens` and `Spanable` emit the code
+//!   reading, printing or spanning each field with the span of the field type, so a field type that
+//!   does not implement the trait is reported at the field instead of at the derive.
+//! - `#[hizli(bound = "T: Trait")]` on the type: extra where predicates for the generated impl.
+//! - `#[hizli(skip)]` on a field: `Parse` initializes it with `Default::default()`,
+//!   `Spanable` ignores it when looking for the first field's span, `ToTokens` does not print it.
+//! - `#[hizli(with = path)]` on a field: `ToTokens` prints it with `path(&field, &mut tokens)`.
+//! - `#[hizli(separator = Token![,])]` on a collection field: `ToTokens` prints a separator
+//!   between its elements, and after the last one too with `#[hizli(trailing)]`. `Parse` reads a
+//!   `Vec` or `Punctuated` of separated elements from the rest of the input, where the separator
+//!   after the last element is optional, or required with `#[hizli(trailing)]`.
 
 pub use hizli_core::*;
-pub use hizli_macros::{Parse, Spanable};
+#[cfg(feature = "testing")]
+pub use hizli_macros::ArbitrarySyntax;
+pub use hizli_macros::{NsAttr, Parse, Spanable, ToTokens};